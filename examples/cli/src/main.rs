@@ -1,4 +1,4 @@
-use chess_lib::{Board, MoveList};
+use chess_lib::{Board, PerftOptions, UciEngine, parse_perft_suite, run_perft_suite_with};
 use std::io::{self, stdin, Write};
 
 fn main() {
@@ -16,7 +16,7 @@ fn main() {
             break;
         }
 
-        print!("Enter move (e.g. e2e4), 'u' to undo, 'r' to reset, 'fen' to input FEN, or 'q' to quit: ");
+        print!("Enter move (e.g. e2e4), 'u' to undo, 'r' to reset, 'fen' to input FEN, 'suite <file> [depth] [engine]' to run a perft suite, 'bisect <engine> <depth>' to check perft against an engine, or 'q' to quit: ");
         io::stdout().flush().unwrap();
 
         let mut input = String::new();
@@ -38,25 +38,83 @@ fn main() {
                 io::stdout().flush().unwrap();
                 let mut fen = String::new();
                 stdin().read_line(&mut fen).unwrap();
-                match Board::new_from_fen(fen.trim()) {
+                match Board::new_from_fen_validated(fen.trim()) {
                     Ok(b) => board = b,
                     Err(e) => println!("Invalid FEN: {}", e),
                 }
                 continue;
             }
+            _ if input.starts_with("bisect ") => {
+                bisect(&board, &input["bisect ".len()..]);
+                continue;
+            }
+            _ if input.starts_with("suite ") => {
+                run_suite(&input["suite ".len()..]);
+                continue;
+            }
             _ => {
-            let mov = board.move_from_algebraic(&input);
-            match mov {
-                Some(m) => {
-                    let mut moves = MoveList::new();
-                    board.generate_legal_moves(board.white_turn, &mut moves);
-                    if moves.contains(&m) {
-                        board.make_move_unchecked(m)
-                    }
-                },
-                None => println!("Not a move"),
+                // Coordinates first, then SAN
+                let mov = board.parse_uci_move(input).map_err(|e| e.to_string()).or_else(|uci| {
+                    board.parse_san(input).map_err(|san| {
+                        // Only mention the coordinate error when the input looked like one
+                        let coordinates = matches!(input.len(), 4 | 5) && input.chars().all(|c| c.is_ascii_alphanumeric());
+                        if coordinates { format!("{} ({})", uci, san) } else { san.to_string() }
+                    })
+                });
+                match mov {
+                    Ok(m) => board.make_move_unchecked(m),
+                    Err(e) => println!("Could not read '{}': {}", input, e),
+                }
             }
-            },
         }
     }
 }
+
+fn run_suite(args: &str) {
+    let mut args = args.split_whitespace();
+    let Some(path) = args.next() else {
+        println!("Usage: suite <file> [depth] [engine]");
+        return;
+    };
+    let depth = args.next().and_then(|d| d.parse().ok()).unwrap_or(4);
+    // Optional engine to diff the divide of each mismatch against
+    let mut engine = match args.next().map(UciEngine::new).transpose() {
+        Ok(engine) => engine,
+        Err(e) => {
+            println!("Engine error: {}", e);
+            return;
+        }
+    };
+
+    let cases = match std::fs::read_to_string(path).map_err(|e| e.to_string()).and_then(|text| parse_perft_suite(&text)) {
+        Ok(cases) => cases,
+        Err(e) => {
+            println!("Could not read suite: {}", e);
+            return;
+        }
+    };
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let options = PerftOptions { threads, hash_entries: 1 << 20 };
+    let report = run_perft_suite_with(&cases, depth, options, |board, depth| {
+        engine.as_mut().and_then(|engine| engine.divide_board(board, depth).ok())
+    });
+
+    for mismatch in &report.mismatches {
+        println!("{}", mismatch);
+    }
+    println!("{} passed, {} failed", report.passed, report.mismatches.len());
+}
+
+fn bisect(board: &Board, args: &str) {
+    let mut args = args.split_whitespace();
+    let (Some(path), Some(depth)) = (args.next(), args.next().and_then(|d| d.parse().ok())) else {
+        println!("Usage: bisect <engine> <depth>");
+        return;
+    };
+    let result = UciEngine::new(path).and_then(|mut engine| board.bisect_perft(depth, &mut engine));
+    match result {
+        Ok(Some(bug)) => println!("{}", bug),
+        Ok(None) => println!("Perft matches the engine"),
+        Err(e) => println!("Engine error: {}", e),
+    }
+}
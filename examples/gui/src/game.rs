@@ -1,7 +1,7 @@
 use std::io::stdin;
 
 use chess_lib::{Board, MoveList, Piece, Tile};
-use macroquad::{miniquad::date::now, prelude::*};
+use macroquad::prelude::*;
 
 mod input;
 
@@ -19,7 +19,6 @@ pub struct Game {
     pub selected_tile: Option<Tile>,
     pub state: GameState,
     pub grace: bool,
-    pub rand: macroquad::rand::RandGenerator,
     pub piece_atlas: Texture2D,
 }
 
@@ -31,16 +30,12 @@ impl Game {
         let piece_atlas = load_texture("assets/PieceAtlas.png").await.unwrap();
         piece_atlas.set_filter(FilterMode::Linear);
 
-        let rand = macroquad::rand::RandGenerator::new();
-        rand.srand(now() as u64);
-
         Self {
             board,
             flipped: false,
             selected_tile: None,
             state: GameState::Playing,
             grace: false,
-            rand,
             piece_atlas,
         }
     }
@@ -91,7 +86,7 @@ impl Game {
         println!("Input FEN string: ");
         let mut buffer = String::new();
         let _ = stdin().read_line(&mut buffer);
-        match Board::new_from_fen(&buffer.trim()) {
+        match Board::new_from_fen_validated(&buffer.trim()) {
             Ok(b) => self.board = b,
             Err(e) => println!("{}", e),
         }
@@ -35,13 +35,10 @@ impl Game {
         }
     
         if is_key_pressed(KeyCode::Space) {
-            let mut moves = chess_lib::MoveList::new();
-            self.board
-                .generate_legal_moves(self.board.white_turn, &mut moves);
-    
-            if !moves.is_empty() {
-                let random_index = self.rand.gen_range(0, moves.len());
-                self.board.make_move_unchecked(moves[random_index]);
+            let result = self.board.search(chess_lib::SearchLimits::time(std::time::Duration::from_millis(500)));
+            if let Some(mov) = result.best_move {
+                println!("{} ({}, depth {})", self.board.move_to_san(&mov), result.score, result.depth);
+                self.board.make_move_unchecked(mov);
             }
         }
     }
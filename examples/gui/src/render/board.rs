@@ -25,11 +25,11 @@ pub fn render_board_squares(board: &Board, flipped: bool) {
                 }
             }
 
-            if white_in_check && tile == board.white.king_tile() {
+            if white_in_check && tile == board.white().king_tile() {
                 color.r = 1.0; color.g *= 0.5; color.b *= 0.5;
             }
 
-            if black_in_check && tile == board.black.king_tile() {
+            if black_in_check && tile == board.black().king_tile() {
                 color.r = 1.0; color.g *= 0.5; color.b *= 0.5;
             }
 
@@ -66,7 +66,7 @@ fn iteration_over_bits() {
     bb.set_bit(t1, true);
     bb.set_bit(t2, true);
 
-    let mut collected: Vec<u8> = bb.clone().map(|t| t.to_u8()).collect();
+    let mut collected: Vec<u8> = bb.map(|t| t.to_u8()).collect();
     collected.sort();
     assert_eq!(collected, vec![7, 15]);
 }
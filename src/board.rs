@@ -7,31 +7,83 @@ pub mod movement;
 pub mod attackgen;
 
 pub mod debug;
+pub mod perft;
 pub mod fen;
 pub mod helper;
+pub mod validate;
+pub mod retro;
+pub mod see;
 
 #[cfg(test)]
 mod tests;
 
 use std::cell::Cell;
 
-use crate::{CastlingRights, Move, Player, Tile};
-#[derive(PartialEq, Eq, Debug, Clone)]
+use crate::nnue::NnueState;
+use crate::{CastlingRights, EvalAccumulator, Move, Piece, Player, Tile};
+#[derive(Debug, Clone)]
 pub struct Board {
-    pub white: Player,
-    pub black: Player,
+    // Only changed through `place_piece`/`remove_piece`/`move_piece`/`edit_players`, which keep the mailbox in sync
+    pub(crate) white: Player,
+    pub(crate) black: Player,
+    // Part of the Zobrist key like `white_turn` and `en_passant`. Write them with their setters,
+    // or call `refresh_zobrist` after writing them directly
     pub castling: CastlingRights,
+    // Piece on every tile, mirrors the player bitboards for O(1) lookups
+    mailbox: [Option<(Piece, bool)>; 64],
 
     pub white_turn: bool,
     pub en_passant: Option<Tile>,
+    // Castling moves are encoded king takes rook and FENs use X-FEN letters
+    pub chess960: bool,
 
     // Store move & its S.A.N string while we have context 
     pub history: Vec<(Move, String)>,
-    repetition_history: Vec<u64>,
+    // Every move made, recorded or not, so it can be taken back. Also used for repetitions
+    undo_stack: Vec<UndoInfo>,
+    pub(crate) zobrist: u64,
+    // Incremental evaluation sums, only maintained once enabled
+    pub(crate) accumulator: Option<EvalAccumulator>,
+    pub(crate) nnue: Option<NnueState>,
 
-    pub half_moves: u8,
+    pub half_moves: u32,
     pub full_move: u32,
 
     white_cache: Cell<Option<bool>>,
     black_cache: Cell<Option<bool>>,
 }
+
+// Evaluation state and check caches are derived from the rest, boards compare equal with or without them
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.white == other.white
+            && self.black == other.black
+            && self.castling == other.castling
+            && self.mailbox == other.mailbox
+            && self.white_turn == other.white_turn
+            && self.en_passant == other.en_passant
+            && self.chess960 == other.chess960
+            && self.history == other.history
+            && self.undo_stack == other.undo_stack
+            && self.zobrist == other.zobrist
+            && self.half_moves == other.half_moves
+            && self.full_move == other.full_move
+    }
+}
+impl Eq for Board {}
+
+/// State a move destroys, pushed by `make_move` and restored by `unmake_move`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct UndoInfo {
+    pub mov: Move,
+    pub captured: Option<Piece>,
+    pub castling: CastlingRights,
+    pub en_passant: Option<Tile>,
+    pub half_moves: u32,
+    // Key of the position before the move
+    pub zobrist: u64,
+    pub white_cache: Option<bool>,
+    pub black_cache: Option<bool>,
+    // Made with `make_move_unchecked`, so it owns the last `history` entry
+    pub recorded: bool,
+}
@@ -1,4 +1,4 @@
-use crate::{Board, GameState, MoveList, Piece, Tile};
+use crate::{Board, GameState, Move, MoveList, Piece, Tile};
 
 impl Board {
     pub fn tile_attacked(&self, tile: Tile, by_white: bool) -> bool {
@@ -55,6 +55,46 @@ impl Board {
         is_checked
     }
 
+    /// Whether a legal move of the side to move would check the opponent, without playing it.
+    pub fn gives_check(&self, mov: &Move) -> bool {
+        let white = self.white_turn;
+        let (player, opponent) = self.get_players(white);
+        let king = opponent.king_tile();
+
+        let Some((piece, _)) = self.get_piece_at_tile(mov.from()) else {
+            return false;
+        };
+
+        // Our pieces and the occupancy once the move is played
+        let mut bb = player.bb;
+        let mut occ = self.occupied();
+        if mov.is_castle() {
+            let (king_to, rook_from, rook_to) = Self::castling_tiles(self.castling, white, mov.flag() == Move::KING_CASTLE);
+            bb[Piece::Rook as usize].set_bit(rook_from, false);
+            bb[Piece::Rook as usize].set_bit(rook_to, true);
+            occ.set_bit(mov.from(), false);
+            occ.set_bit(rook_from, false);
+            occ.set_bit(king_to, true);
+            occ.set_bit(rook_to, true);
+        } else {
+            bb[piece as usize].set_bit(mov.from(), false);
+            bb[mov.promoted_to().unwrap_or(piece) as usize].set_bit(mov.to(), true);
+            occ.set_bit(mov.from(), false);
+            occ.set_bit(mov.to(), true);
+        }
+        if mov.is_en_passant() {
+            occ.set_bit(mov.to().backward(white).unwrap(), false);
+        }
+
+        let straight = bb[Piece::Rook as usize] | bb[Piece::Queen as usize];
+        let diagonal = bb[Piece::Bishop as usize] | bb[Piece::Queen as usize];
+        let checkers = (king.rook_attacks(occ) & straight)
+            | (king.bishop_attacks(occ) & diagonal)
+            | (king.knight_attacks() & bb[Piece::Knight as usize])
+            | (king.pawn_attacks(!white) & bb[Piece::Pawn as usize]);
+        checkers.some()
+    }
+
     pub fn is_checkmate(&self, white: bool) -> bool {
         if !self.is_in_check(white) {
             return false;
@@ -76,11 +116,20 @@ impl Board {
         self.half_moves >= 100 
     }
     pub fn three_fold_rep(&self) -> bool {
-        let current = self.to_zobrist_hash();
-        self.repetition_history.iter()
-            .filter(|&&hash| hash == current)
-            .take(3)
-            .count() == 3
+        // Only positions since the last capture or pawn move can repeat
+        self.undo_stack.iter()
+            .rev()
+            .take(self.half_moves as usize)
+            .filter(|undo| undo.zobrist == self.zobrist)
+            .take(2)
+            .count() == 2
+    }
+    /// Whether the position occurred before, searches treat the first repetition as a draw.
+    pub fn is_repetition(&self) -> bool {
+        self.undo_stack.iter()
+            .rev()
+            .take(self.half_moves as usize)
+            .any(|undo| undo.zobrist == self.zobrist)
     }
     pub fn insufficient_material(&self) -> bool
     {
@@ -1,58 +1,89 @@
 use std::cell::Cell;
 
-use crate::{Board, CastlingRights, Piece, Player, Tile};
+use crate::{Board, CastlingRights, FenError, FenMode, Piece, Player, Tile};
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Board {
     pub fn new() -> Self {
-        Self {
+        let mut board = Self {
             white: Player::new_white(),
             black: Player::new_black(),
             castling: CastlingRights::ALL,
+            mailbox: [None; 64],
 
             white_turn: true,
             history: Vec::new(),
-            repetition_history: Vec::new(),
+            undo_stack: Vec::new(),
             en_passant: None,
+            chess960: false,
 
             half_moves: 0,
             full_move: 1,
 
+            zobrist: 0,
+            accumulator: None,
+            nnue: None,
+
             white_cache: Cell::new(None),
             black_cache: Cell::new(None),
-        }
+        };
+        board.refresh_mailbox();
+        board.refresh_zobrist();
+        board
     }
     pub fn new_empty() -> Self {
         Self {
             white: Player::new_empty(),
             black: Player::new_empty(),
             castling: CastlingRights::NONE,
+            mailbox: [None; 64],
 
             white_turn: true,
             history: Vec::new(),
-            repetition_history: Vec::new(),
+            undo_stack: Vec::new(),
             en_passant: None,
+            chess960: false,
 
             half_moves: 0,
             full_move: 1,
 
+            zobrist: 0,
+            accumulator: None,
+            nnue: None,
+
             white_cache: Cell::new(None),
             black_cache: Cell::new(None),
         }
     }
-    pub fn new_from_fen(fen: &str) -> Result<Self, String> {
+    /// Parse a FEN in `FenMode::OptionalClocks`, use `new_from_fen_with` for `FenMode::Strict`.
+    pub fn new_from_fen(fen: &str) -> Result<Self, FenError> {
+        Self::new_from_fen_with(fen, FenMode::OptionalClocks)
+    }
+    pub fn new_from_fen_with(fen: &str, mode: FenMode) -> Result<Self, FenError> {
+        let strict = mode != FenMode::Lenient;
         let mut board = Board::new_empty();
-        let mut fields = fen.split_whitespace();
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        let invalid = |field: usize, text: &str| FenError::InvalidField { field, text: text.to_string() };
 
-        let piece_placement = fields.next().ok_or("Missing piece placement in FEN")?;
-        let active_color = fields.next().ok_or("Missing active color in FEN")?;
-        let castling_rights = fields.next().ok_or("Missing castling rights in FEN")?;
-        let en_passant = fields.next().ok_or("Missing en passant square in FEN")?;
-        let half_move = fields.next().unwrap_or("0");
-        let full_move = fields.next().unwrap_or("1");
+        if mode == FenMode::Strict && fields.len() > 6 {
+            return Err(FenError::ExtraField { field: 6, text: fields[6].to_string() });
+        }
+        // Lenient parsing defaults every field after the placement, optional clocks only the last two
+        let field = |i: usize, default: &'static str| match fields.get(i) {
+            Some(f) => Ok(*f),
+            None if i == 0 || mode == FenMode::Strict || (strict && i < 4) => Err(FenError::MissingField { field: i }),
+            None => Ok(default),
+        };
 
+        let piece_placement = field(0, "")?;
         let ranks: Vec<&str> = piece_placement.split('/').collect();
         if ranks.len() != 8 {
-            return Err("Invalid FEN: expected 8 ranks".to_string());
+            return Err(invalid(0, piece_placement));
         }
 
         for (rank_idx, rank_str) in ranks.iter().enumerate() {
@@ -64,7 +95,7 @@ impl Board {
                 }
 
                 if file >= 8 {
-                    return Err(format!("Too many pieces in rank {}", 8 - rank_idx));
+                    return Err(invalid(0, rank_str));
                 }
 
                 let is_white = ch.is_uppercase();
@@ -75,59 +106,90 @@ impl Board {
                     'r' => Piece::Rook,
                     'q' => Piece::Queen,
                     'k' => Piece::King,
-                    _ => return Err(format!("Invalid piece character: {}", ch)),
+                    _ => return Err(invalid(0, rank_str)),
                 };
 
                 let rank = 7 - rank_idx as u8;
-                let square = rank * 8 + file;
-
-                if let Some(tile) = Tile::new_index(square) {
-                    if is_white {
-                        board.white.place_piece(piece, tile);
-                    } else {
-                        board.black.place_piece(piece, tile);
-                    }
-                } else {
-                    return Err(format!("Invalid square index: {}", square));
-                }
-
+                board.place_piece(piece, is_white, Tile::new_xy(file, rank).unwrap());
                 file += 1;
             }
 
             if file != 8 {
-                return Err(format!("Incomplete rank {} in FEN", 8 - rank_idx));
+                return Err(invalid(0, rank_str));
             }
         }
 
-        board.white_turn = active_color == "w";
-        board.castling = CastlingRights::from_fen(castling_rights);
-
-        board.en_passant = if en_passant != "-" {
-            let bytes = en_passant.as_bytes();
-            if bytes.len() != 2 {
-                return Err(format!("Invalid en passant square: {}", en_passant));
-            }
-
-            let file = bytes[0];
-            let rank = bytes[1];
-            if !(b'a'..=b'h').contains(&file) || !(b'1'..=b'8').contains(&rank) {
-                return Err(format!("Invalid en passant square: {}", en_passant));
-            }
+        let active_color = field(1, "w")?;
+        board.white_turn = match active_color {
+            "w" => true,
+            "b" => false,
+            _ if strict => return Err(invalid(1, active_color)),
+            _ => !active_color.starts_with(['b', 'B']),
+        };
 
-            let file_idx = file - b'a';
-            let rank_idx = rank - b'1';
-            Tile::new_xy(rank_idx, file_idx)
-        } else {
-            None
+        let castling_rights = field(2, "-")?;
+        board.castling = board.parse_castling(castling_rights, strict)?;
+
+        let en_passant = field(3, "-")?;
+        // The square must be behind a pawn that just made a double push
+        let ep_rank = if board.white_turn { 5 } else { 2 };
+        board.en_passant = match Tile::from_str(en_passant) {
+            _ if en_passant == "-" => None,
+            Some(tile) if tile.get_coords().1 == ep_rank => Some(tile),
+            _ if strict => return Err(invalid(3, en_passant)),
+            _ => None,
         };
 
-        board.half_moves = half_move
-            .parse()
-            .map_err(|_| format!("Invalid half move count: {}", half_move))?;
-        board.full_move = full_move
-            .parse()
-            .map_err(|_| format!("Invalid full move count: {}", full_move))?;
+        let half_move = field(4, "0")?;
+        board.half_moves = match half_move.parse() {
+            Ok(n) => n,
+            Err(_) if strict => return Err(invalid(4, half_move)),
+            Err(_) => 0,
+        };
+        let full_move = field(5, "1")?;
+        board.full_move = match full_move.parse() {
+            Ok(n) if n > 0 => n,
+            _ if strict => return Err(invalid(5, full_move)),
+            _ => 1,
+        };
 
+        board.refresh_zobrist();
         Ok(board)
     }
+    /// The Chess960 start position with Scharnagl number `index`, 518 is the standard setup.
+    pub fn new_chess960(index: u16) -> Option<Self> {
+        if index >= 960 {
+            return None;
+        }
+        // Knight placements among the five files left after bishops and queen
+        const KNIGHTS: [(usize, usize); 10] = [
+            (0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4),
+        ];
+
+        let mut rank: [Option<Piece>; 8] = [None; 8];
+        let mut n = index as usize;
+        rank[(n % 4) * 2 + 1] = Some(Piece::Bishop);
+        n /= 4;
+        rank[(n % 4) * 2] = Some(Piece::Bishop);
+        n /= 4;
+
+        let mut place_nth_empty = |nth: usize, piece: Piece| {
+            let file = (0..8).filter(|&f| rank[f].is_none()).nth(nth).unwrap();
+            rank[file] = Some(piece);
+        };
+        place_nth_empty(n % 6, Piece::Queen);
+        n /= 6;
+        let (first, second) = KNIGHTS[n];
+        place_nth_empty(second, Piece::Knight);
+        place_nth_empty(first, Piece::Knight);
+        for piece in [Piece::Rook, Piece::King, Piece::Rook] {
+            place_nth_empty(0, piece);
+        }
+
+        let back: String = rank.iter().map(|p| p.unwrap().to_fen_char(false)).collect();
+        let fen = format!("{}/pppppppp/8/8/8/8/PPPPPPPP/{} w KQkq - 0 1", back, back.to_uppercase());
+        let mut board = Self::new_from_fen(&fen).ok()?;
+        board.chess960 = true;
+        Some(board)
+    }
 }
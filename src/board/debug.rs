@@ -1,4 +1,6 @@
-use crate::{Board, MoveList};
+use std::fmt::Write;
+
+use crate::{Board, Move, MoveList};
 
 impl Board {
     pub fn positions(&mut self, depth: usize) -> i64 {
@@ -8,38 +10,63 @@ impl Board {
 
         let mut moves = MoveList::new();
         self.generate_legal_moves(self.white_turn, &mut moves);
+        if depth == 1 {
+            return moves.len() as i64;
+        }
         let mut positions = 0;
 
         for &m in moves.iter() {
-            self.make_move_unchecked(m);
-            if depth == 1 {
-                positions += 1;
-            } else {
-                positions += self.positions(depth - 1);
-            }
-            self.undo_move();
+            self.make_move(m);
+            positions += self.positions(depth - 1);
+            self.unmake_move();
         }
 
         positions
     }
-    pub fn positions_divide(&mut self, depth: usize) -> i64 {
+    /// Call `check` on this position and every one reachable within `depth` moves, played with
+    /// `make_move_unchecked` and taken back with `undo_move`. Tests use it to compare incrementally
+    /// updated state with a recomputation.
+    #[cfg(test)]
+    pub(crate) fn for_each_position(&mut self, depth: usize, check: &mut impl FnMut(&Board)) {
+        check(self);
         if depth == 0 {
-            return 1;
+            return;
         }
-
         let mut moves = MoveList::new();
-        // self.generate_moves(self.white_turn, &mut moves);
         self.generate_legal_moves(self.white_turn, &mut moves);
-        let mut positions = 0;
-
         for &m in moves.iter() {
             self.make_move_unchecked(m);
-            let count = self.positions(depth - 1);
-            positions += count;
-            println!("{}, {}", self.move_to_san(&m), count);
+            self.for_each_position(depth - 1, check);
             self.undo_move();
         }
+    }
+    /// Node count below every root move, see `format_divide` for printing.
+    pub fn positions_divide(&mut self, depth: usize) -> Vec<(Move, u64)> {
+        if depth == 0 {
+            return Vec::new();
+        }
 
-        positions
+        let mut moves = MoveList::new();
+        self.generate_legal_moves(self.white_turn, &mut moves);
+
+        let mut divide = Vec::with_capacity(moves.len());
+        for &m in moves.iter() {
+            self.make_move(m);
+            divide.push((m, self.positions(depth - 1) as u64));
+            self.unmake_move();
+        }
+
+        divide
+    }
+}
+
+/// Format a divide exactly like Stockfish's `go perft`, so the two can be diffed line by line.
+pub fn format_divide(divide: &[(Move, u64)]) -> String {
+    let mut s = String::new();
+    for (mov, count) in divide {
+        let _ = writeln!(s, "{}: {}", mov.to_uci(), count);
     }
+    let total: u64 = divide.iter().map(|(_, count)| count).sum();
+    let _ = write!(s, "\nNodes searched: {}\n", total);
+    s
 }
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::{Board, GameState, Move, MoveList, Piece, Tile};
+use crate::{Board, CastlingRights, FenError, GameState, Move, MoveList, Piece, SanError, Tile, UciMoveError};
 
 impl Board {
     pub fn to_fen(&self) -> String {
@@ -42,9 +42,8 @@ impl Board {
         fen.push(if self.white_turn { 'w' } else { 'b' });
 
         // Castling rights
-        let castling = self.castling.to_fen();
         fen.push(' ');
-        fen.push_str(&castling);
+        fen.push_str(&self.castling_fen(false));
 
         // En passant
         fen.push(' ');
@@ -72,157 +71,264 @@ impl Board {
             pgn.push_str(&format!("{} ", san));
         }
 
-        let state = self.get_state();
-        if let GameState::Checkmate(w) = state {
-            if w {
-                pgn.push_str("0-1");
+        pgn.push_str(self.pgn_result());
+        pgn
+    }
+    /// The PGN result token for the current state, `*` while the game is still going.
+    pub fn pgn_result(&self) -> &'static str {
+        match self.get_state() {
+            GameState::Checkmate(true) => "0-1",
+            GameState::Checkmate(false) => "1-0",
+            GameState::Stalemate(_)
+            | GameState::FiftyMoveRule
+            | GameState::InsufficientMaterial
+            | GameState::ThreeRepetition => "1/2-1/2",
+            _ => "*",
+        }
+    }
+    /// FEN with Shredder castling letters, the rook files ( `HAha` ) instead of `KQkq`.
+    pub fn to_shredder_fen(&self) -> String {
+        let fen = self.to_fen();
+        let mut fields: Vec<&str> = fen.split(' ').collect();
+        let castling = self.castling_fen(true);
+        fields[2] = &castling;
+        fields.join(" ")
+    }
+    // X-FEN only names the rook file if it is not the outermost one, Shredder always does
+    fn castling_fen(&self, shredder: bool) -> String {
+        if self.castling.to_u8() == 0 {
+            return "-".to_string();
+        }
+        let mut s = String::new();
+        for (white, kingside) in [(true, true), (true, false), (false, true), (false, false)] {
+            let right = CastlingRights::side(white, kingside);
+            if !self.castling.contains(right) {
+                continue;
+            }
+            let rook_tile = self.castling.rook_tile(right);
+            let (player, _) = self.get_players(white);
+            let rooks = player.bb[Piece::Rook as usize];
+            let outermost = rooks.iter()
+                .filter(|t| t.get_coords().1 == rook_tile.get_coords().1)
+                .all(|t| if kingside { t.get_coords().0 <= rook_tile.get_coords().0 } else { t.get_coords().0 >= rook_tile.get_coords().0 });
+
+            let c = if shredder || !outermost {
+                (b'a' + rook_tile.get_coords().0) as char
+            } else if kingside {
+                'k'
             } else {
-                pgn.push_str("1-0");
+                'q'
+            };
+            s.push(if white { c.to_ascii_uppercase() } else { c });
+        }
+        s
+    }
+    /// Read a castling field in standard, X-FEN or Shredder-FEN notation, the pieces must be placed.
+    /// Rights that need Chess960 castling switch the board to it. Strict parsing rejects unknown
+    /// letters, repeated rights and rights without a rook, lenient parsing skips them.
+    pub(crate) fn parse_castling(&mut self, field: &str, strict: bool) -> Result<CastlingRights, FenError> {
+        let invalid = || FenError::InvalidField { field: 2, text: field.to_string() };
+        let mut rights = CastlingRights::NONE;
+        if field == "-" {
+            return Ok(rights);
+        }
+        for c in field.chars() {
+            let white = c.is_ascii_uppercase();
+            let rank = if white { 0 } else { 7 };
+            let (player, _) = self.get_players(white);
+            let king_file = match player.bb[Piece::King as usize].to_bit() {
+                Some(t) if t.get_coords().1 == rank => Some(t.get_coords().0),
+                _ => None,
+            };
+            let rook_files: Vec<u8> = player.bb[Piece::Rook as usize]
+                .iter()
+                .filter(|t| t.get_coords().1 == rank)
+                .map(|t| t.get_coords().0)
+                .collect();
+
+            let parsed = king_file.and_then(|king_file| {
+                let (kingside, file) = match c.to_ascii_lowercase() {
+                    // Outermost rook on that side
+                    'k' => (true, rook_files.iter().copied().filter(|&f| f > king_file).max()?),
+                    'q' => (false, rook_files.iter().copied().filter(|&f| f < king_file).min()?),
+                    f @ 'a'..='h' => {
+                        let file = f as u8 - b'a';
+                        if !rook_files.contains(&file) {
+                            return None;
+                        }
+                        (file > king_file, file)
+                    }
+                    _ => return None,
+                };
+                Some((king_file, kingside, file))
+            });
+
+            let Some((king_file, kingside, file)) = parsed else {
+                if strict {
+                    return Err(invalid());
+                }
+                continue;
+            };
+            let right = CastlingRights::side(white, kingside);
+            if rights.contains(right) {
+                if strict {
+                    return Err(invalid());
+                }
+                continue;
             }
-        } else if matches!(state, GameState::Stalemate(_)) 
-            || state == GameState::FiftyMoveRule 
-            || state == GameState::InsufficientMaterial 
-            || state == GameState::ThreeRepetition {
-                pgn.push_str("1/2-1/2");
-        } else {
-            pgn.push('*');
+            if king_file != 4 || file != if kingside { 7 } else { 0 } {
+                self.chess960 = true;
+            }
+            rights.insert_with_file(right, file);
         }
-        pgn
+        Ok(rights)
     }
     pub fn move_from_algebraic(&self, s: &str) -> Option<Move> {
-        let s = s.trim();
-
-        // Castling
-        if s.eq_ignore_ascii_case("O-O") || s == "0-0" {
-            let (from, to) = if self.white_turn {
-                (Tile::E1, Tile::G1)
-            } else {
-                (Tile::E8, Tile::G8)
-            };
-            return Some(self.create_move(from, to, Piece::King, None, None));
+        self.parse_san(s).ok()
+    }
+    /// Parse S.A.N into the one legal move it names, only legal moves count for disambiguation.
+    /// Tolerates check and annotation suffixes, `e.p.`, long forms like `Ng1-f3`, `:` for captures,
+    /// promotions without `=`, `0-0` castling and lowercase piece letters.
+    pub fn parse_san(&self, s: &str) -> Result<Move, SanError> {
+        let mut s = s.trim().trim_end_matches(['+', '#', '!', '?']).trim_end();
+        for suffix in ["e.p.", "ep"] {
+            if let Some(rest) = s.strip_suffix(suffix) {
+                s = rest.trim_end();
+            }
         }
 
-        if s.eq_ignore_ascii_case("O-O-O") || s == "0-0-0" {
-            let (from, to) = if self.white_turn {
-                (Tile::E1, Tile::C1)
-            } else {
-                (Tile::E8, Tile::C8)
+        let mut moves = MoveList::new();
+        self.generate_legal_moves(self.white_turn, &mut moves);
+
+        // Castling
+        let castle = s.replace('0', "O").to_ascii_uppercase();
+        if castle == "O-O" || castle == "O-O-O" {
+            return match self.castling_move(self.white_turn, castle == "O-O") {
+                Some(mov) if moves.contains(&mov) => Ok(mov),
+                _ => Err(SanError::Illegal),
             };
-            return Some(self.create_move(from, to, Piece::King, None, None));
         }
 
-        let mut chars = s.chars().peekable();
-
-        // Piece type
-        let piece = match chars.peek() {
-            Some('N') => { chars.next(); Piece::Knight }
-            Some('B') => { chars.next(); Piece::Bishop }
-            Some('R') => { chars.next(); Piece::Rook }
-            Some('Q') => { chars.next(); Piece::Queen }
-            Some('K') => { chars.next(); Piece::King }
-            _ => Piece::Pawn,
+        let piece = match s.chars().next() {
+            Some('N' | 'n') => Piece::Knight,
+            Some('B') => Piece::Bishop,
+            Some('R' | 'r') => Piece::Rook,
+            Some('Q' | 'q') => Piece::Queen,
+            Some('K' | 'k') => Piece::King,
+            Some('a'..='h') => Piece::Pawn,
+            _ => return Err(SanError::Unparseable),
         };
-
-        let mut disamb_file = None;
-        let mut disamb_rank = None;
-
-        // Disambiguation
-        while let Some(&c) = chars.peek() {
-            if c == 'x' {
-                break;
-            } else if c.is_ascii_digit() {
-                disamb_rank = Some(c as u8 - b'1');
-                chars.next();
-            } else if c.is_ascii_alphabetic() {
-                disamb_file = Some(c as u8 - b'a');
-                chars.next();
-            } else {
-                break;
-            }
+        if piece != Piece::Pawn {
+            return self.parse_san_piece(&s[1..], piece, &moves);
         }
-
-        let is_capture = matches!(chars.peek(), Some('x'));
-        if is_capture {
-            chars.next();
+        // A lowercase `b` is the b-file first, a bishop if that fails
+        match self.parse_san_piece(s, Piece::Pawn, &moves) {
+            Err(e) if s.starts_with('b') => self.parse_san_piece(&s[1..], Piece::Bishop, &moves).map_err(|_| e),
+            result => result,
         }
-
-        // Destination
-        let dest_file = chars.next()? as u8 - b'a';
-        let dest_rank = chars.next()? as u8 - b'1';
-        let to = Tile::new_xy(dest_file, dest_rank)?;
-
-        // Promotion
-        let promotion = if chars.peek() == Some(&'=') {
-            chars.next();
-            match chars.next()? {
-                'Q' => Some(Piece::Queen),
-                'R' => Some(Piece::Rook),
-                'B' => Some(Piece::Bishop),
-                'N' => Some(Piece::Knight),
-                _ => return None,
+    }
+    // The rest of the S.A.N after the piece letter, matched against legal `moves`
+    fn parse_san_piece(&self, s: &str, piece: Piece, moves: &MoveList) -> Result<Move, SanError> {
+        let mut chars: Vec<char> = s.chars().filter(|c| !matches!(c, 'x' | ':' | '-' | '=' | '(' | ')' | '/')).collect();
+
+        let promotion = match chars.last() {
+            Some(c) if piece == Piece::Pawn && c.is_ascii_alphabetic() => {
+                let promotion = match c.to_ascii_uppercase() {
+                    'N' => Piece::Knight,
+                    'B' => Piece::Bishop,
+                    'R' => Piece::Rook,
+                    'Q' => Piece::Queen,
+                    _ => return Err(SanError::Unparseable),
+                };
+                chars.pop();
+                Some(promotion)
             }
-        } else {
-            None
+            _ => None,
         };
 
-        let (player, _) = self.get_players(self.white_turn);
+        // Destination is always last, anything before it disambiguates
+        if chars.len() < 2 || chars.len() > 4 {
+            return Err(SanError::Unparseable);
+        }
+        let (prefix, dest) = chars.split_at(chars.len() - 2);
+        let to = Tile::from_str(&dest.iter().collect::<String>()).ok_or(SanError::Unparseable)?;
+        let mut file = None;
+        let mut rank = None;
+        for &c in prefix {
+            match c {
+                'a'..='h' if file.is_none() && rank.is_none() => file = Some(c as u8 - b'a'),
+                '1'..='8' if rank.is_none() => rank = Some(c as u8 - b'1'),
+                _ => return Err(SanError::Unparseable),
+            }
+        }
 
-        // Filter matching pieces that can move to destination
-        let mut candidates: Vec<Tile> = player.bb[piece as usize]
+        let to_here = |m: &&Move| m.to() == to && m.promoted_to() == promotion && !m.is_castle();
+        let candidates: Vec<Move> = moves
             .iter()
-            .filter(|&from_tile| {
-                let mut moves = MoveList::new();
-                self.generate_psuedo_moves_from(from_tile, &mut moves);
-                moves.contains_move(from_tile, to)
-            })
+            .filter(to_here)
+            .filter(|m| self.get_piece_at_tile(m.from()).map(|(p, _)| p) == Some(piece))
+            .filter(|m| file.is_none_or(|f| m.from().get_coords().0 == f))
+            .filter(|m| rank.is_none_or(|r| m.from().get_coords().1 == r))
+            .copied()
             .collect();
 
-        // Apply disambiguation
-        if let Some(f) = disamb_file {
-            candidates.retain(|&t| t.get_coords().0 == f);
-        }
-        if let Some(r) = disamb_rank {
-            candidates.retain(|&t| t.get_coords().1 == r);
+        match candidates.as_slice() {
+            [mov] => Ok(*mov),
+            [] if moves.iter().any(|m| to_here(&m)) => Err(SanError::WrongPiece),
+            [] => Err(SanError::Illegal),
+            _ => Err(SanError::Ambiguous),
         }
+    }
+    /// Parse a coordinate move like `e2e4` or `e7e8q` into the matching legal move.
+    /// Castling is accepted both as the king's destination and as king takes rook.
+    pub fn parse_uci_move(&self, s: &str) -> Result<Move, UciMoveError> {
+        let unparseable = || UciMoveError::Unparseable(s.to_string());
+        let (from, to, promotion) = match s.len() {
+            4 | 5 if s.is_ascii() => (&s[0..2], &s[2..4], &s[4..]),
+            _ => return Err(unparseable()),
+        };
+        let from = Tile::from_str(from).ok_or_else(unparseable)?;
+        let to = Tile::from_str(to).ok_or_else(unparseable)?;
+        let promotion = match promotion {
+            "" => None,
+            "n" => Some(Piece::Knight),
+            "b" => Some(Piece::Bishop),
+            "r" => Some(Piece::Rook),
+            "q" => Some(Piece::Queen),
+            _ => return Err(unparseable()),
+        };
 
-        // Ambiguity check
-        if candidates.len() != 1 {
-            return None;
+        let mut moves = MoveList::new();
+        self.generate_legal_moves_from(from, &mut moves);
+        if let Some(&mov) = moves.iter().find(|m| m.to() == to && m.promoted_to() == promotion) {
+            return Ok(mov);
         }
 
-        let from = candidates[0];
-
-        // Determine captured piece
-        let captured = if is_capture {
-            self.get_piece_at_tile(to)
-                .map(|(p, _)| p)
-                .or_else(|| {
-                    if piece == Piece::Pawn && Some(to) == self.en_passant {
-                        Some(Piece::Pawn)
-                    } else {
-                        None
-                    }
-                })
-        } else {
-            None
-        };
-
-        Some(self.create_move(from, to, piece, captured, promotion))
+        // The other castling encoding, e1g1 in Chess960 or e1h1 in standard chess
+        if promotion.is_none() && self.get_piece_at_tile(from) == Some((Piece::King, self.white_turn)) {
+            for kingside in [true, false] {
+                let (king_to, rook_from, _) = Self::castling_tiles(self.castling, self.white_turn, kingside);
+                if let Some(mov) = self.castling_move(self.white_turn, kingside)
+                    && (to == king_to || to == rook_from)
+                    && moves.contains(&mov)
+                {
+                    return Ok(mov);
+                }
+            }
+        }
+        Err(UciMoveError::Illegal(s.to_string()))
     }
     pub fn move_to_san(&self, mov: &Move) -> String {
-        let piece = mov.piece();
+        let piece = self.get_piece_at_tile(mov.from()).map_or(Piece::Pawn, |(p, _)| p);
         let from = mov.from();
         let to = mov.to();
-        let capture = mov.capture();
         let promo = mov.promoted_to();
 
         // Castling
-        if piece == Piece::King {
-            if (from == Tile::E1 && to == Tile::G1) || (from == Tile::E8 && to == Tile::G8) {
-                return "O-O".to_string();
-            } else if (from == Tile::E1 && to == Tile::C1) || (from == Tile::E8 && to == Tile::C8) {
-                return "O-O-O".to_string();
-            }
+        match mov.flag() {
+            Move::KING_CASTLE => return "O-O".to_string(),
+            Move::QUEEN_CASTLE => return "O-O-O".to_string(),
+            _ => {}
         }
 
         let mut s = String::new();
@@ -238,7 +344,7 @@ impl Board {
         }
 
         // Capture
-        if capture.is_some() {
+        if mov.is_capture() {
             if piece == Piece::Pawn {
                 let (from_file, _) = from.get_coords();
                 s.push((b'a' + from_file) as char);
@@ -260,7 +366,7 @@ impl Board {
         s
     }
     pub fn get_disambig(&self, mov: &Move) -> Option<String> {
-        let piece = mov.piece();
+        let (piece, _) = self.get_piece_at_tile(mov.from())?;
         if piece == Piece::Pawn || piece == Piece::King {
             return None;
         }
@@ -302,12 +408,12 @@ impl Board {
 
         let mut result = String::new();
         if !same_file {
-            result.push((b'a' + from_file as u8) as char);
+            result.push((b'a' + from_file) as char);
         } else if !same_rank {
-            result.push((b'1' + from_rank as u8) as char);
+            result.push((b'1' + from_rank) as char);
         } else {
-            result.push((b'a' + from_file as u8) as char);
-            result.push((b'1' + from_rank as u8) as char);
+            result.push((b'a' + from_file) as char);
+            result.push((b'1' + from_rank) as char);
         }
 
         Some(result)
@@ -1,4 +1,5 @@
-use crate::{Bitboard, Board, Move, Piece, Player, Tile};
+use crate::zobrist::piece_key;
+use crate::{Bitboard, Board, CastlingRights, DecodedMove, Move, Piece, Player, Tile};
 
 impl Board {
     pub fn create_move(
@@ -9,19 +10,69 @@ impl Board {
         captured: Option<Piece>,
         promotion: Option<Piece>,
     ) -> Move {
-        Move::new(
-            from,
-            to,
-            piece,
-            captured,
-            self.en_passant,
-            self.castling,
-            promotion,
-            self.white_cache.get(),
-            self.black_cache.get(),
-            self.half_moves,
+        if let Some(p) = promotion {
+            return Move::new_promotion(from, to, p, captured.is_some());
+        }
+        if piece == Piece::King
+            && captured.is_none()
+            && let Some((_, white)) = self.get_piece_at_tile(from)
+        {
+            for kingside in [true, false] {
+                if let Some(castle) = self.castling_move(white, kingside)
+                    && castle.from() == from
+                    && castle.to() == to
+                {
+                    return castle;
+                }
+            }
+        }
+        let flag = match piece {
+            Piece::Pawn if captured.is_some() && Some(to) == self.en_passant => Move::EN_PASSANT,
+            Piece::Pawn if from.get_coords().1.abs_diff(to.get_coords().1) == 2 => Move::DOUBLE_PUSH,
+            _ if captured.is_some() => Move::CAPTURE,
+            _ => Move::QUIET,
+        };
+        Move::new(from, to, flag)
+    }
+    /// The castling move for a side if the right is held, legal or not.
+    /// Encoded as king takes rook in Chess960, king to its destination otherwise.
+    pub fn castling_move(&self, white: bool, kingside: bool) -> Option<Move> {
+        if !self.castling.contains(CastlingRights::side(white, kingside)) {
+            return None;
+        }
+        let (player, _) = self.get_players(white);
+        let king = player.bb[Piece::King as usize].to_bit()?;
+        let (king_to, rook_from, _) = Self::castling_tiles(self.castling, white, kingside);
+        let flag = if kingside { Move::KING_CASTLE } else { Move::QUEEN_CASTLE };
+        Some(Move::new(king, if self.chess960 { rook_from } else { king_to }, flag))
+    }
+    /// King destination, rook origin and rook destination of a castling move.
+    pub(crate) fn castling_tiles(rights: CastlingRights, white: bool, kingside: bool) -> (Tile, Tile, Tile) {
+        let rank = if white { 0 } else { 7 };
+        let (king_file, rook_file) = if kingside { (6, 5) } else { (2, 3) };
+        (
+            Tile::new_xy(king_file, rank).unwrap(),
+            rights.rook_tile(CastlingRights::side(white, kingside)),
+            Tile::new_xy(rook_file, rank).unwrap(),
         )
     }
+    /// Recover the moving and captured pieces of a packed move in this position.
+    /// Returns `None` if the side to move has no piece on the from tile.
+    pub fn decode(&self, raw: u16) -> Option<DecodedMove> {
+        let mov = Move::from_u16(raw);
+        let (piece, white) = self.get_piece_at_tile(mov.from())?;
+        if white != self.white_turn {
+            return None;
+        }
+        let capture = if mov.is_en_passant() {
+            Some(Piece::Pawn)
+        } else if mov.is_capture() {
+            self.get_piece_at_tile(mov.to()).map(|(p, _)| p)
+        } else {
+            None
+        };
+        Some(DecodedMove { mov, piece, capture })
+    }
     pub fn occupied(&self) -> Bitboard {
         self.white.pieces | self.black.pieces
     }
@@ -31,26 +82,140 @@ impl Board {
     pub fn current_players(&self) -> (&Player, &Player) {
         self.get_players(self.white_turn)
     }
+    /// Read only, edit pieces with `place_piece`/`remove_piece`/`move_piece` or `edit_players`.
+    pub fn white(&self) -> &Player {
+        &self.white
+    }
+    pub fn black(&self) -> &Player {
+        &self.black
+    }
     pub fn get_players(&self, white: bool) -> (&Player, &Player) {
         match white {
             true => (&self.white, &self.black),
             false => (&self.black, &self.white),
         }
     }
-    pub fn get_players_mut(&mut self, white: bool) -> (&mut Player, &mut Player) {
+    pub(crate) fn get_players_mut(&mut self, white: bool) -> (&mut Player, &mut Player) {
         match white {
             true => (&mut self.white, &mut self.black),
             false => (&mut self.black, &mut self.white),
         }
     }
+    #[inline(always)]
     pub fn get_piece_at_tile(&self, tile: Tile) -> Option<(Piece, bool)> {
-        let white_piece = self.white.get_piece(tile);
-        let black_piece = self.black.get_piece(tile);
-        match (white_piece, black_piece) {
-            (None, None) => None,
-            (None, Some(p)) => Some((p, false)),
-            (Some(p), None) => Some((p, true)),
-            (Some(_), Some(_)) => panic!("Two pieces are overlapping"),
+        self.mailbox[tile.to_usize()]
+    }
+
+    // Modifications, these keep the players, the mailbox, the Zobrist key and the check caches in sync
+    /// Put a piece on `tile`, replacing whatever stood there.
+    pub fn place_piece(&mut self, piece: Piece, white: bool, tile: Tile) {
+        self.edit_pieces(|board| {
+            if let Some((old, old_white)) = board.take_piece(tile) {
+                board.zobrist ^= piece_key(old, old_white, tile);
+            }
+            board.put_piece(piece, white, tile);
+            board.zobrist ^= piece_key(piece, white, tile);
+        });
+    }
+    pub fn remove_piece(&mut self, tile: Tile) -> Option<(Piece, bool)> {
+        self.edit_pieces(|board| {
+            let (piece, white) = board.take_piece(tile)?;
+            board.zobrist ^= piece_key(piece, white, tile);
+            Some((piece, white))
+        })
+    }
+    /// Move the piece on `from` to `to`, replacing whatever stood there. Does nothing if `from` is empty.
+    pub fn move_piece(&mut self, from: Tile, to: Tile) {
+        let Some((piece, white)) = self.get_piece_at_tile(from) else {
+            return;
+        };
+        if from == to {
+            return;
+        }
+        self.edit_pieces(|board| {
+            if let Some((old, old_white)) = board.take_piece(to) {
+                board.zobrist ^= piece_key(old, old_white, to);
+            }
+            board.shift_piece(from, to);
+            board.zobrist ^= piece_key(piece, white, from) ^ piece_key(piece, white, to);
+        });
+    }
+    /// Edit the players directly, then rebuild everything derived from them. For bulk changes,
+    /// where `board.white.place_piece(piece, tile)` used to be written before the players were read only.
+    pub fn edit_players<R>(&mut self, edit: impl FnOnce(&mut Player, &mut Player) -> R) -> R {
+        let result = edit(&mut self.white, &mut self.black);
+        self.refresh_mailbox();
+        self.refresh_zobrist();
+        self.white_cache.set(None);
+        self.black_cache.set(None);
+        if self.accumulator.is_some() {
+            self.enable_accumulator();
+        }
+        if let Some(nnue) = self.nnue.take() {
+            self.enable_nnue(nnue.network);
+        }
+        result
+    }
+    // Whether en passant is possible and whether a king is in check can change with any piece
+    fn edit_pieces<R>(&mut self, edit: impl FnOnce(&mut Self) -> R) -> R {
+        self.zobrist ^= self.en_passant_key();
+        let result = edit(self);
+        self.zobrist ^= self.en_passant_key();
+        self.white_cache.set(None);
+        self.black_cache.set(None);
+        result
+    }
+
+    // Raw edits for make/unmake, which take care of the key and caches themselves
+    pub(crate) fn put_piece(&mut self, piece: Piece, white: bool, tile: Tile) {
+        debug_assert!(self.mailbox[tile.to_usize()].is_none(), "Two pieces are overlapping");
+        let (player, _) = self.get_players_mut(white);
+        player.place_piece(piece, tile);
+        self.mailbox[tile.to_usize()] = Some((piece, white));
+        if let Some(acc) = &mut self.accumulator {
+            acc.add(piece, white, tile);
+        }
+        if let Some(nnue) = &mut self.nnue {
+            nnue.placed(piece, white, tile, (&self.white, &self.black));
+        }
+    }
+    pub(crate) fn take_piece(&mut self, tile: Tile) -> Option<(Piece, bool)> {
+        let (piece, white) = self.mailbox[tile.to_usize()].take()?;
+        let (player, _) = self.get_players_mut(white);
+        player.remove_piece_type(piece, tile);
+        if let Some(acc) = &mut self.accumulator {
+            acc.remove(piece, white, tile);
+        }
+        if let Some(nnue) = &mut self.nnue {
+            nnue.removed(piece, white, tile, (&self.white, &self.black));
+        }
+        Some((piece, white))
+    }
+    pub(crate) fn shift_piece(&mut self, from: Tile, to: Tile) {
+        let Some((piece, white)) = self.mailbox[from.to_usize()].take() else {
+            return;
+        };
+        debug_assert!(self.mailbox[to.to_usize()].is_none(), "Two pieces are overlapping");
+        let (player, _) = self.get_players_mut(white);
+        player.move_piece(from, to);
+        self.mailbox[to.to_usize()] = Some((piece, white));
+        if let Some(acc) = &mut self.accumulator {
+            acc.remove(piece, white, from);
+            acc.add(piece, white, to);
+        }
+        if let Some(nnue) = &mut self.nnue {
+            nnue.moved(piece, white, from, to, (&self.white, &self.black));
+        }
+    }
+
+    // Rebuild the mailbox from the players
+    pub(crate) fn refresh_mailbox(&mut self) {
+        self.mailbox = [None; 64];
+        for (piece, tile) in self.white.get_all_pieces() {
+            self.mailbox[tile.to_usize()] = Some((piece, true));
+        }
+        for (piece, tile) in self.black.get_all_pieces() {
+            self.mailbox[tile.to_usize()] = Some((piece, false));
         }
     }
 }
@@ -1,16 +1,46 @@
-use std::collections::HashMap;
-
-use crate::{Bitboard, Board, CastlingRights, MoveList, Piece, Tile};
+use crate::{Bitboard, Board, GenType, MoveList, Piece, Tile};
 
 impl Board {
     pub fn generate_legal_moves(&self, white: bool, moves: &mut MoveList) {
-        let (player, _) = self.get_players(white);
+        self.generate_stage(white, GenType::Legal, moves);
+    }
+
+    /// Generate one stage of the legal moves, see `GenType`.
+    pub fn generate_moves(&self, white: bool, stage: GenType, moves: &mut MoveList) {
+        match stage {
+            GenType::Evasions => {
+                if self.get_checkers(white).some() {
+                    self.generate_stage(white, GenType::Legal, moves);
+                }
+            }
+            GenType::QuietChecks => {
+                let mut quiets = MoveList::new();
+                self.generate_stage(white, GenType::Quiets, &mut quiets);
+                for m in quiets.iter() {
+                    if self.gives_check(m) {
+                        moves.push(*m);
+                    }
+                }
+            }
+            _ => self.generate_stage(white, stage, moves),
+        }
+    }
+
+    fn generate_stage(&self, white: bool, stage: GenType, moves: &mut MoveList) {
+        let (player, opponent) = self.get_players(white);
         let checkers = self.get_checkers(white);
         let checkers_count = checkers.count_ones();
 
         let king_tile = player.king_tile();
 
-        self.generate_king_moves(king_tile, white, moves);
+        // Destinations allowed by the stage, pawns sort out promotions themselves
+        let dest = match stage {
+            GenType::Captures => opponent.pieces,
+            GenType::Quiets => !self.occupied(),
+            _ => Bitboard::ALL,
+        };
+
+        self.generate_king_moves(king_tile, white, dest, stage != GenType::Captures, moves);
 
         // Double check ( King moves only )
         if checkers_count > 1 {
@@ -36,37 +66,36 @@ impl Board {
 
         // Pawns
         for pawn_tile in player.bb[Piece::Pawn as usize] {
-            let pin_mask = pinned.get(&pawn_tile).copied().unwrap_or(Bitboard::ALL);
-            let move_mask = pin_mask & targets;
-            self.generate_pawn_moves(pawn_tile, white, Some(move_mask), moves);
+            let pin_mask = pin_mask(pinned, king_tile, pawn_tile);
+            self.pawn_moves(pawn_tile, white, pin_mask, targets, stage, moves);
         }
 
         // Knights
         for knight_tile in player.bb[Piece::Knight as usize] {
-            if pinned.contains_key(&knight_tile) {
+            if pinned.get_bit(knight_tile) {
                 continue;
             }
-            self.generate_knight_moves(knight_tile, white, Some(targets), moves);
+            self.generate_knight_moves(knight_tile, white, Some(targets & dest), moves);
         }
 
         // Bishops
         for bishop_tile in player.bb[Piece::Bishop as usize] {
-            let pin_mask = pinned.get(&bishop_tile).copied().unwrap_or(Bitboard::ALL);
-            let move_mask = pin_mask & targets;
+            let pin_mask = pin_mask(pinned, king_tile, bishop_tile);
+            let move_mask = pin_mask & targets & dest;
             self.generate_sliding_moves(bishop_tile, white, false, true, Some(move_mask), moves);
         }
 
         // Rooks
         for rook_tile in player.bb[Piece::Rook as usize] {
-            let pin_mask = pinned.get(&rook_tile).copied().unwrap_or(Bitboard::ALL);
-            let move_mask = pin_mask & targets;
+            let pin_mask = pin_mask(pinned, king_tile, rook_tile);
+            let move_mask = pin_mask & targets & dest;
             self.generate_sliding_moves(rook_tile, white, true, false, Some(move_mask), moves);
         }
 
         // Queens
         for queen_tile in player.bb[Piece::Queen as usize] {
-            let pin_mask = pinned.get(&queen_tile).copied().unwrap_or(Bitboard::ALL);
-            let move_mask = pin_mask & targets;
+            let pin_mask = pin_mask(pinned, king_tile, queen_tile);
+            let move_mask = pin_mask & targets & dest;
             self.generate_sliding_moves(queen_tile, white, true, true, Some(move_mask), moves);
         }
     }
@@ -103,12 +132,20 @@ impl Board {
         } else {
             None
         };
-        let mask = combine_masks(pinned.get(&tile).copied(), check_targets);
+        let pin = pinned.get_bit(tile).then(|| player.king_tile().get_line(tile));
+        let mask = combine_masks(pin, check_targets);
 
         match piece {
-            Piece::Pawn => self.generate_pawn_moves(tile, white, mask, moves),
+            Piece::Pawn => self.pawn_moves(
+                tile,
+                white,
+                pin.unwrap_or(Bitboard::ALL),
+                check_targets.unwrap_or(Bitboard::ALL),
+                GenType::Legal,
+                moves,
+            ),
             Piece::Knight => {
-                if pinned.contains_key(&tile) {
+                if pinned.get_bit(tile) {
                     return;
                 }
                 self.generate_knight_moves(tile, white, mask, moves);
@@ -116,26 +153,23 @@ impl Board {
             Piece::Bishop => self.generate_sliding_moves(tile, white, false, true, mask, moves),
             Piece::Rook => self.generate_sliding_moves(tile, white, true, false, mask, moves),
             Piece::Queen => self.generate_sliding_moves(tile, white, true, true, mask, moves),
-            Piece::King => self.generate_king_moves(tile, white, moves),
+            Piece::King => self.generate_king_moves(tile, white, Bitboard::ALL, true, moves),
         }
     }
     pub fn generate_psuedo_moves_from(&self, tile: Tile, moves: &mut MoveList) {
-        match self.get_piece_at_tile(tile) {
-            Some((p, _)) => {
-                match p {
-                    Piece::Pawn => self.generate_pawn_moves(tile, self.white_turn, None, moves),
-                    Piece::Knight => self.generate_knight_moves(tile, self.white_turn, None, moves),
-                    Piece::Bishop => self.generate_sliding_moves(tile, self.white_turn, false, true, None, moves),
-                    Piece::Rook => self.generate_sliding_moves(tile, self.white_turn, true, false, None, moves),
-                    Piece::Queen => self.generate_sliding_moves(tile, self.white_turn, true, true, None, moves),
-                    Piece::King => self.generate_king_moves(tile, self.white_turn, moves),
-                }
-            },
-            None => return,
+        if let Some((p, _)) = self.get_piece_at_tile(tile) {
+            match p {
+                Piece::Pawn => self.generate_pawn_moves(tile, self.white_turn, None, moves),
+                Piece::Knight => self.generate_knight_moves(tile, self.white_turn, None, moves),
+                Piece::Bishop => self.generate_sliding_moves(tile, self.white_turn, false, true, None, moves),
+                Piece::Rook => self.generate_sliding_moves(tile, self.white_turn, true, false, None, moves),
+                Piece::Queen => self.generate_sliding_moves(tile, self.white_turn, true, true, None, moves),
+                Piece::King => self.generate_king_moves(tile, self.white_turn, Bitboard::ALL, true, moves),
+            }
         }
     }
 
-    fn get_checkers(&self, white: bool) -> Bitboard {
+    pub(crate) fn get_checkers(&self, white: bool) -> Bitboard {
         let (player, attacker) = self.get_players(white);
         let occ = self.occupied();
         let mut checkers = Bitboard::EMPTY;
@@ -154,66 +188,26 @@ impl Board {
 
         checkers
     }
-    fn get_pinned_pieces(&self, white: bool) -> HashMap<Tile, Bitboard> {
-        use crate::Piece::{Bishop, Queen, Rook};
-        let mut pins = HashMap::new();
-
-        let (player, _) = self.get_players(white);
+    /// Friendly pieces standing alone between their king and an enemy slider.
+    pub fn get_pinned_pieces(&self, white: bool) -> Bitboard {
+        let (player, opponent) = self.get_players(white);
         let kt = player.king_tile();
         let occ = self.occupied();
 
-        let directions: [(i8, i8); 8] = [
-            (0, 1),
-            (1, 0),
-            (0, -1),
-            (-1, 0),
-            (1, 1),
-            (1, -1),
-            (-1, -1),
-            (-1, 1),
-        ];
-
-        for (dx, dy) in directions {
-            let mut current = kt;
-            let mut maybe_pinned: Option<Tile> = None;
-
-            while let Some(next) = current.offset(dx, dy) {
-                current = next;
-
-                if !occ.get_bit(current) {
-                    continue;
-                }
-
-                if player.pieces.get_bit(current) {
-                    if maybe_pinned.is_none() {
-                        maybe_pinned = Some(current);
-                    } else {
-                        // Second friendly piece => No pin
-                        break;
-                    }
-                } else {
-                    if let Some(pinned_tile) = maybe_pinned {
-                        if let Some((ptype, _)) = self.get_piece_at_tile(current) {
-                            let is_diag = dx.abs() == dy.abs();
-                            let is_straight = dx == 0 || dy == 0;
-
-                            if ptype == Queen
-                                || (ptype == Rook && is_straight)
-                                || (ptype == Bishop && is_diag)
-                            {
-                                pins.insert(
-                                    pinned_tile,
-                                    kt.get_between(current) | current.to_mask(),
-                                );
-                            }
-                        }
-                    }
-                    break;
-                }
+        // X-ray from the king through everything but enemy pieces
+        let straight = opponent.bb[Piece::Rook as usize] | opponent.bb[Piece::Queen as usize];
+        let diagonal = opponent.bb[Piece::Bishop as usize] | opponent.bb[Piece::Queen as usize];
+        let snipers = (kt.rook_attacks(opponent.pieces) & straight)
+            | (kt.bishop_attacks(opponent.pieces) & diagonal);
+
+        let mut pinned = Bitboard::EMPTY;
+        for sniper in snipers {
+            let blockers = kt.get_between(sniper) & occ;
+            if blockers.count_ones() == 1 {
+                pinned |= blockers & player.pieces;
             }
         }
-
-        pins
+        pinned
     }
 
     pub fn generate_pawn_moves(
@@ -223,26 +217,49 @@ impl Board {
         targets: Option<Bitboard>,
         moves: &mut MoveList,
     ) {
+        let targets = targets.unwrap_or(Bitboard::ALL);
+        self.pawn_moves(tile, white, Bitboard::ALL, targets, GenType::Legal, moves);
+    }
+
+    /// Pawn moves restricted to `pin` and to the tiles resolving a check in `check`.
+    /// Promotions belong to the capture stage, other pushes to the quiet stage.
+    fn pawn_moves(
+        &self,
+        tile: Tile,
+        white: bool,
+        pin: Bitboard,
+        check: Bitboard,
+        stage: GenType,
+        moves: &mut MoveList,
+    ) {
+        let targets = pin & check;
+        let captures = stage != GenType::Quiets;
+        let quiets = stage != GenType::Captures;
+
         // Single forward
-        if let Some(one_step) = tile.forward(white) {
-            if self.occupied().get_bit(one_step) {
-            } else {
-                if targets.map_or(true, |mask| mask.get_bit(one_step)) {
-                    self.try_push_pawn_move(tile, one_step, white, None, moves);
-                }
+        if let Some(one_step) = tile.forward(white)
+            && !self.occupied().get_bit(one_step)
+        {
+            let allowed = if one_step.is_promotion(white) { captures } else { quiets };
+            if allowed && targets.get_bit(one_step) {
+                self.try_push_pawn_move(tile, one_step, white, None, moves);
+            }
 
-                // Double forward
-                if tile.is_pawn_start(white) {
-                    if let Some(two_step) = one_step.forward(white) {
-                        if self.occupied().get_bit(two_step) {
-                        } else if targets.map_or(true, |mask| mask.get_bit(two_step)) {
-                            moves.push(self.create_move(tile, two_step, Piece::Pawn, None, None));
-                        }
-                    }
-                }
+            // Double forward
+            if quiets
+                && tile.is_pawn_start(white)
+                && let Some(two_step) = one_step.forward(white)
+                && !self.occupied().get_bit(two_step)
+                && targets.get_bit(two_step)
+            {
+                moves.push(self.create_move(tile, two_step, Piece::Pawn, None, None));
             }
         }
 
+        if !captures {
+            return;
+        }
+
         for maybe_target in [
             tile.left(white).and_then(|t| t.forward(white)),
             tile.right(white).and_then(|t| t.forward(white)),
@@ -252,8 +269,12 @@ impl Board {
                 None => continue,
             };
 
-            // En passant capture check
-            if Some(to) == self.en_passant && targets.map_or(true, |mask| mask.get_bit(to.backward(white).unwrap())) {
+            // En passant capture check, the captured pawn may be the checker itself
+            if Some(to) == self.en_passant {
+                let captured_tile = to.backward(white).unwrap();
+                if !pin.get_bit(to) || !(check.get_bit(captured_tile) || check.get_bit(to)) {
+                    continue;
+                }
                 let king_tile = if white {
                     self.white.bb[Piece::King as usize].to_bit().unwrap()
                 } else {
@@ -270,7 +291,7 @@ impl Board {
             }
 
             // Normal capture
-            if !self.is_square_occupied_by_enemy(to, white) || !targets.map_or(true, |mask| mask.get_bit(to)) {
+            if !self.is_square_occupied_by_enemy(to, white) || !targets.get_bit(to) {
                 continue;
             }
             let captured = self.get_piece_at_tile(to).map(|(p, _)| p);
@@ -409,8 +430,15 @@ impl Board {
     }
 
     #[inline]
-    fn generate_king_moves(&self, tile: Tile, white: bool, moves: &mut MoveList) {
-        let mut attacks = tile.king_attacks();
+    fn generate_king_moves(
+        &self,
+        tile: Tile,
+        white: bool,
+        targets: Bitboard,
+        castle: bool,
+        moves: &mut MoveList,
+    ) {
+        let mut attacks = tile.king_attacks() & targets;
         let friendly_mask = if white {
             self.white.pieces
         } else {
@@ -430,44 +458,38 @@ impl Board {
             ));
         }
 
-        if attack_mask.get_bit(tile) {
+        if !castle || attack_mask.get_bit(tile) {
             return; 
         }
 
-        let castling_rights = self.castling;
         let occupied = self.occupied();
+        let (_, opponent) = self.get_players(white);
+        let rank_sliders = opponent.bb[Piece::Rook as usize] | opponent.bb[Piece::Queen as usize];
 
-
-        if white {
-            if castling_rights.contains(CastlingRights::WHITE_KINGSIDE)
-                && (occupied & (Tile::F1.to_mask() | Tile::G1.to_mask())).none()
-                && (attack_mask & (Tile::F1.to_mask() | Tile::G1.to_mask())).none()
-            {
-                moves.push(self.create_move(tile, Tile::G1, Piece::King, None, None));
+        for kingside in [true, false] {
+            let Some(castle) = self.castling_move(white, kingside) else {
+                continue;
+            };
+            let (king_to, rook_from, rook_to) = Self::castling_tiles(self.castling, white, kingside);
+            if self.get_piece_at_tile(rook_from) != Some((Piece::Rook, white)) {
+                continue;
             }
 
-            if castling_rights.contains(CastlingRights::WHITE_QUEENSIDE)
-                && (occupied & (Tile::D1.to_mask() | Tile::C1.to_mask() | Tile::B1.to_mask()))
-                    .none()
-                && (attack_mask & (Tile::D1.to_mask() | Tile::C1.to_mask())).none()
-            {
-                moves.push(self.create_move(tile, Tile::C1, Piece::King, None, None));
+            // Everything both pieces cross must be empty, apart from the two of them
+            let movers = tile.to_mask() | rook_from.to_mask();
+            let king_path = tile.get_between(king_to) | king_to.to_mask();
+            let rook_path = rook_from.get_between(rook_to) | rook_to.to_mask();
+            if (occupied & (king_path | rook_path) & !movers).some() {
+                continue;
             }
-        } else {
-            if castling_rights.contains(CastlingRights::BLACK_KINGSIDE)
-                && (occupied & (Tile::F8.to_mask() | Tile::G8.to_mask())).none()
-                && (attack_mask & (Tile::F8.to_mask() | Tile::G8.to_mask())).none()
-            {
-                moves.push(self.create_move(tile, Tile::G8, Piece::King, None, None));
+            if (attack_mask & king_path).some() {
+                continue;
             }
-
-            if castling_rights.contains(CastlingRights::BLACK_QUEENSIDE)
-                && (occupied & (Tile::D8.to_mask() | Tile::C8.to_mask() | Tile::B8.to_mask()))
-                    .none()
-                && (attack_mask & (Tile::D8.to_mask() | Tile::C8.to_mask())).none()
-            {
-                moves.push(self.create_move(tile, Tile::C8, Piece::King, None, None));
+            // In Chess960 the rook may have been shielding the king's destination along the rank
+            if (king_to.rook_attacks((occupied & !movers) | rook_to.to_mask()) & rank_sliders).some() {
+                continue;
             }
+            moves.push(castle);
         }
     }
 
@@ -477,6 +499,14 @@ impl Board {
         opponent.pieces.get_bit(square)
     }
 }
+#[inline(always)]
+fn pin_mask(pinned: Bitboard, king_tile: Tile, tile: Tile) -> Bitboard {
+    if pinned.get_bit(tile) {
+        king_tile.get_line(tile)
+    } else {
+        Bitboard::ALL
+    }
+}
 fn combine_masks(a: Option<Bitboard>, b: Option<Bitboard>) -> Option<Bitboard> {
     match (a, b) {
         (Some(a), Some(b)) => Some(a & b),
@@ -1,4 +1,5 @@
-use crate::{MoveResult, Board, CastlingRights, Move, MoveError, MoveList, Piece, Tile};
+use crate::{MoveResult, board::UndoInfo, Board, CastlingRights, Move, MoveError, MoveList, Piece, Tile};
+use crate::zobrist::{castling_key, piece_key, consts::SIDE_TO_MOVE};
 
 impl Board {
     pub fn try_move_piece(
@@ -34,6 +35,21 @@ impl Board {
             if w != self.white_turn {
                 return Err(MoveError::WrongTurn);
             }
+
+            // Chess960 castling is the king taking its own rook, not a friendly capture
+            let castle = [true, false]
+                .into_iter()
+                .filter_map(|kingside| self.castling_move(w, kingside))
+                .find(|m| m.from() == from && m.to() == to);
+            if let Some(mov) = castle {
+                let mut legal = MoveList::new();
+                self.generate_legal_moves_from(from, &mut legal);
+                if !legal.contains(&mov) {
+                    return Err(MoveError::IllegalMove);
+                }
+                self.make_move_unchecked(mov);
+                return Ok(MoveResult::MoveApplied(self.get_state()));
+            }
             
             let capture = match self.get_piece_at_tile(to) {
                 Some((p, w)) => {
@@ -73,161 +89,183 @@ impl Board {
             }
             self.make_move_unchecked(mov);
 
-            return Ok(MoveResult::MoveApplied(self.get_state()));
+            Ok(MoveResult::MoveApplied(self.get_state()))
         } else {
-            return Err(MoveError::NoPieceSelected);
+            Err(MoveError::NoPieceSelected)
         }
     }
+    /// Play a move and record its S.A.N in `history`, for GUIs and PGN output.
     pub fn make_move_unchecked(&mut self, mov: Move) {
+        let san = self.make_move_san(mov);
+        self.history.push((mov, san));
+        if let Some(undo) = self.undo_stack.last_mut() {
+            undo.recorded = true;
+        }
+    }
+    /// Play a move with `make_move` and return its S.A.N, with the check or mate suffix.
+    /// Nothing is recorded in `history`.
+    pub fn make_move_san(&mut self, mov: Move) -> String {
         let mut san = self.move_to_san(&mov);
-        let (player, opponent) = if self.white_turn {
-            (&mut self.white, &mut self.black)
+        self.make_move(mov);
+
+        if self.is_checkmate(self.white_turn) {
+            san.push('#');
+        } else if self.is_in_check(self.white_turn) {
+            san.push('+');
+        }
+        san
+    }
+    /// Take back the last move played with `make_move_unchecked`.
+    /// Returns `false` and leaves the board alone if the last move was made with `make_move` instead.
+    pub fn undo_move(&mut self) -> bool {
+        if !self.undo_stack.last().is_some_and(|undo| undo.recorded) {
+            return false;
+        }
+        self.unmake_move();
+        true
+    }
+
+    /// Play a move without any S.A.N or `history` bookkeeping, for perft and search.
+    /// Moves made this way must be taken back with `unmake_move`.
+    pub fn make_move(&mut self, mov: Move) {
+        let white = self.white_turn;
+        let (piece, _) = self.get_piece_at_tile(mov.from()).expect("No piece to move");
+        let captured_tile = if mov.is_en_passant() {
+            mov.to().backward(white).unwrap()
         } else {
-            self.full_move += 1;
-            (&mut self.black, &mut self.white)
+            mov.to()
+        };
+        let captured = if mov.is_capture() {
+            self.get_piece_at_tile(captured_tile).map(|(p, _)| p)
+        } else {
+            None
         };
+        self.undo_stack.push(UndoInfo {
+            mov,
+            captured,
+            castling: self.castling,
+            en_passant: self.en_passant,
+            half_moves: self.half_moves,
+            zobrist: self.zobrist,
+            white_cache: self.white_cache.get(),
+            black_cache: self.black_cache.get(),
+            recorded: false,
+        });
+
+        // Strip the side dependant parts of the key, they are added back once the move is done
+        let mut key = self.zobrist ^ castling_key(self.castling) ^ self.en_passant_key();
+
+        if !white {
+            self.full_move += 1;
+        }
         self.half_moves += 1;
 
-        if mov.piece() == Piece::Pawn
+        if piece == Piece::Pawn
         {
             self.half_moves = 0;
         }
-        if let Some(p) = mov.capture() {
+        if let Some(p) = captured {
             self.half_moves = 0;
-            let target_tile = if mov.en_passant() == Some(mov.to()) {
-                mov.to().backward(self.white_turn).unwrap()
-            } else {
-                mov.to()
-            };
-            opponent.remove_piece_type(p, target_tile);
+            self.take_piece(captured_tile);
+            key ^= piece_key(p, !white, captured_tile);
 
             if p == Piece::Rook {
-                let rights = match mov.to() {
-                    Tile::A1 => CastlingRights::WHITE_QUEENSIDE,
-                    Tile::H1 => CastlingRights::WHITE_KINGSIDE,
-
-                    Tile::A8 => CastlingRights::BLACK_QUEENSIDE,
-                    Tile::H8 => CastlingRights::BLACK_KINGSIDE,
-                    _ => CastlingRights::NONE,
-                };
-                self.castling.remove(rights);
+                self.castling.remove(self.rights_of_rook(captured_tile));
             }
         }
 
-        player.move_piece(mov.from(), mov.to());
+        if mov.is_castle() {
+            let (king_to, rook_from, rook_to) = Self::castling_tiles(self.castling, white, mov.flag() == Move::KING_CASTLE);
+            // The king and rook may land on each other's tiles in Chess960, lift both first
+            self.take_piece(mov.from());
+            self.take_piece(rook_from);
+            self.put_piece(Piece::King, white, king_to);
+            self.put_piece(Piece::Rook, white, rook_to);
+            key ^= piece_key(Piece::King, white, mov.from()) ^ piece_key(Piece::King, white, king_to);
+            key ^= piece_key(Piece::Rook, white, rook_from) ^ piece_key(Piece::Rook, white, rook_to);
+        } else {
+            self.shift_piece(mov.from(), mov.to());
+            key ^= piece_key(piece, white, mov.from()) ^ piece_key(piece, white, mov.to());
+        }
         self.en_passant = None;
 
         if let Some(p) = mov.promoted_to() {
-            player.remove_piece(mov.to());
-            player.place_piece(p, mov.to());
+            self.take_piece(mov.to());
+            self.put_piece(p, white, mov.to());
+            key ^= piece_key(Piece::Pawn, white, mov.to()) ^ piece_key(p, white, mov.to());
         }
 
-        if mov.piece() == Piece::King {
-            let rights = match self.white_turn {
-                true => CastlingRights::WHITE_KINGSIDE | CastlingRights::WHITE_QUEENSIDE,
-                false => CastlingRights::BLACK_KINGSIDE | CastlingRights::BLACK_QUEENSIDE,
-            };
-            self.castling.remove(rights);
-            match (self.white_turn, mov.from(), mov.to()) {
-                (true, Tile::E1, Tile::G1) => {
-                    player.move_piece(Tile::H1, Tile::F1);
-                }
-                (true, Tile::E1, Tile::C1) => {
-                    player.move_piece(Tile::A1, Tile::D1);
-                }
-                (false, Tile::E8, Tile::G8) => {
-                    player.move_piece(Tile::H8, Tile::F8);
-                }
-                (false, Tile::E8, Tile::C8) => {
-                    player.move_piece(Tile::A8, Tile::D8);
-                }
-                _ => {}
-            }
+        if piece == Piece::King {
+            self.castling.remove(CastlingRights::side(white, true) | CastlingRights::side(white, false));
         }
-        if mov.piece() == Piece::Rook {
-            let rights = match mov.from() {
-                Tile::A1 => CastlingRights::WHITE_QUEENSIDE,
-                Tile::H1 => CastlingRights::WHITE_KINGSIDE,
-
-                Tile::A8 => CastlingRights::BLACK_QUEENSIDE,
-                Tile::H8 => CastlingRights::BLACK_KINGSIDE,
-                _ => CastlingRights::NONE,
-            };
-            self.castling.remove(rights);
+        if piece == Piece::Rook {
+            self.castling.remove(self.rights_of_rook(mov.from()));
         }
-        if mov.piece() == Piece::Pawn
-            && (mov.from().get_coords().0 == mov.to().get_coords().0)
-            && (i8::abs(mov.from().get_coords().1 as i8 - mov.to().get_coords().1 as i8) == 2)
-        {
-            self.en_passant = Some(mov.to().backward(self.white_turn).unwrap());
+        if mov.is_double_push() {
+            self.en_passant = Some(mov.to().backward(white).unwrap());
         }
         
-        self.white_turn = !self.white_turn;
+        self.white_turn = !white;
         self.white_cache.set(None);
         self.black_cache.set(None);
-        self.repetition_history.push(self.to_zobrist_hash());
 
-        if self.is_checkmate(self.white_turn) {
-            san.push('#');
-        } else if self.is_in_check(self.white_turn) {
-            san.push('+');
-        }
-        self.history.push((mov, san));
-        
+        self.zobrist = key ^ SIDE_TO_MOVE ^ castling_key(self.castling) ^ self.en_passant_key();
+        debug_assert_eq!(self.zobrist, self.to_zobrist_hash(), "incremental zobrist key diverged");
     }
-    pub fn undo_move(&mut self) {
-        if let Some((last_move, _)) = self.history.pop() {
-            let (player, opponent) = match !self.white_turn {
-                true => (&mut self.white, &mut self.black),
-                false => {
-                    self.full_move -= 1;
-                    (&mut self.black, &mut self.white)
-                },
-            };
-            if last_move.promoted_to().is_some() {
-                player.remove_piece(last_move.to());
-                player.place_piece(Piece::Pawn, last_move.to());
+    /// Take back the last move, whichever way it was made, dropping its `history` entry if it has one.
+    pub fn unmake_move(&mut self) {
+        if let Some(undo) = self.undo_stack.pop() {
+            if undo.recorded {
+                self.history.pop();
             }
-            player.move_piece(last_move.to(), last_move.from());
-
-            if let Some(captured) = last_move.capture() {
-                if last_move.piece() == Piece::Pawn
-                    && last_move.en_passant() == Some(last_move.to())
-                {
-                    opponent.place_piece(
-                        Piece::Pawn,
-                        last_move.to().backward(!self.white_turn).unwrap(),
-                    );
-                } else {
-                    opponent.place_piece(captured, last_move.to());
+            let last_move = undo.mov;
+            let white = !self.white_turn;
+            if !white {
+                self.full_move -= 1;
+            }
+            if last_move.is_castle() {
+                let (king_to, rook_from, rook_to) = Self::castling_tiles(undo.castling, white, last_move.flag() == Move::KING_CASTLE);
+                self.take_piece(king_to);
+                self.take_piece(rook_to);
+                self.put_piece(Piece::King, white, last_move.from());
+                self.put_piece(Piece::Rook, white, rook_from);
+            } else {
+                if last_move.promoted_to().is_some() {
+                    self.take_piece(last_move.to());
+                    self.put_piece(Piece::Pawn, white, last_move.to());
                 }
+                self.shift_piece(last_move.to(), last_move.from());
             }
 
-            if last_move.piece() == Piece::King {
-                match (!self.white_turn, last_move.from(), last_move.to()) {
-                    (true, Tile::E1, Tile::G1) => {
-                        player.move_piece(Tile::F1, Tile::H1);
-                    }
-                    (true, Tile::E1, Tile::C1) => {
-                        player.move_piece(Tile::D1, Tile::A1);
-                    }
-                    (false, Tile::E8, Tile::G8) => {
-                        player.move_piece(Tile::F8, Tile::H8);
-                    }
-                    (false, Tile::E8, Tile::C8) => {
-                        player.move_piece(Tile::D8, Tile::A8);
-                    }
-                    _ => {}
+            if let Some(captured) = undo.captured {
+                if last_move.is_en_passant() {
+                    self.put_piece(Piece::Pawn, !white, last_move.to().backward(white).unwrap());
+                } else {
+                    self.put_piece(captured, !white, last_move.to());
                 }
             }
-            self.castling = last_move.prev_castle();
-            self.en_passant = last_move.en_passant();
-            self.white_cache.set(last_move.white_cache());
-            self.black_cache.set(last_move.black_cache());
-            self.half_moves = last_move.prev_half_moves();
-            self.repetition_history.pop();
 
-            self.white_turn = !self.white_turn;
+            self.castling = undo.castling;
+            self.en_passant = undo.en_passant;
+            self.white_cache.set(undo.white_cache);
+            self.black_cache.set(undo.black_cache);
+            self.half_moves = undo.half_moves;
+            self.zobrist = undo.zobrist;
+
+            self.white_turn = white;
+            debug_assert_eq!(self.zobrist, self.to_zobrist_hash(), "incremental zobrist key diverged");
+        }
+    }
+
+    // Rights lost when a rook leaves or is captured on `tile`
+    fn rights_of_rook(&self, tile: Tile) -> CastlingRights {
+        let mut rights = CastlingRights::NONE;
+        for (white, kingside) in [(true, true), (true, false), (false, true), (false, false)] {
+            let right = CastlingRights::side(white, kingside);
+            if self.castling.contains(right) && self.castling.rook_tile(right) == tile {
+                rights.insert(right);
+            }
         }
+        rights
     }
 }
@@ -0,0 +1,226 @@
+use std::ops::AddAssign;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::{Bitboard, Board, Move, MoveList};
+
+/// Leaf move counters, matching the tables on the chessprogramming wiki.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PerftStats {
+    pub nodes: u64,
+    pub captures: u64,
+    pub en_passant: u64,
+    pub castles: u64,
+    pub promotions: u64,
+    pub checks: u64,
+    pub discovered_checks: u64,
+    pub double_checks: u64,
+    pub checkmates: u64,
+}
+
+impl AddAssign for PerftStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.nodes += rhs.nodes;
+        self.captures += rhs.captures;
+        self.en_passant += rhs.en_passant;
+        self.castles += rhs.castles;
+        self.promotions += rhs.promotions;
+        self.checks += rhs.checks;
+        self.discovered_checks += rhs.discovered_checks;
+        self.double_checks += rhs.double_checks;
+        self.checkmates += rhs.checkmates;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PerftOptions {
+    /// Threads the root moves are split across, 1 searches on the calling thread.
+    pub threads: usize,
+    /// Entries in each thread's hash table, 0 disables it.
+    pub hash_entries: usize,
+}
+
+impl Default for PerftOptions {
+    fn default() -> Self {
+        Self { threads: 1, hash_entries: 0 }
+    }
+}
+
+// Results of already counted subtrees, keyed by Zobrist key and depth
+struct PerftTable<T> {
+    entries: Vec<Option<(u64, usize, T)>>,
+}
+
+impl<T: Copy> PerftTable<T> {
+    fn new(size: usize) -> Self {
+        Self { entries: vec![None; size] }
+    }
+    fn get(&self, key: u64, depth: usize) -> Option<T> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        match self.entries[key as usize % self.entries.len()] {
+            Some((k, d, value)) if k == key && d == depth => Some(value),
+            _ => None,
+        }
+    }
+    fn insert(&mut self, key: u64, depth: usize, value: T) {
+        if self.entries.is_empty() {
+            return;
+        }
+        let len = self.entries.len();
+        self.entries[key as usize % len] = Some((key, depth, value));
+    }
+}
+
+impl Board {
+    /// Count the leaf nodes `depth` plies deep.
+    pub fn perft(&self, depth: usize, options: PerftOptions) -> u64 {
+        self.run_perft(depth, options, Self::perft_nodes)
+    }
+    /// Count and classify the leaf moves `depth` plies deep.
+    pub fn perft_stats(&self, depth: usize, options: PerftOptions) -> PerftStats {
+        if depth == 0 {
+            return PerftStats { nodes: 1, ..Default::default() };
+        }
+        // The root split hands every thread `depth - 1`, stats need at least one ply of moves
+        let options = if depth == 1 { PerftOptions { threads: 1, ..options } } else { options };
+        self.run_perft(depth, options, Self::perft_leaf_stats)
+    }
+
+    fn run_perft<T, F>(&self, depth: usize, options: PerftOptions, search: F) -> T
+    where
+        T: Copy + Default + AddAssign + Send,
+        F: Fn(&mut Board, usize, &mut PerftTable<T>) -> T + Sync,
+    {
+        if depth == 0 || options.threads <= 1 {
+            let mut board = self.clone();
+            let mut table = PerftTable::new(options.hash_entries);
+            return search(&mut board, depth, &mut table);
+        }
+
+        let mut moves = MoveList::new();
+        self.generate_legal_moves(self.white_turn, &mut moves);
+        let next = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..options.threads)
+                .map(|_| {
+                    let mut board = self.clone();
+                    let (next, moves, search) = (&next, &moves, &search);
+                    scope.spawn(move || {
+                        let mut table = PerftTable::new(options.hash_entries);
+                        let mut total = T::default();
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            if i >= moves.len() {
+                                break;
+                            }
+                            board.make_move(moves[i]);
+                            total += search(&mut board, depth - 1, &mut table);
+                            board.unmake_move();
+                        }
+                        total
+                    })
+                })
+                .collect();
+
+            let mut total = T::default();
+            for handle in handles {
+                total += handle.join().expect("perft thread panicked");
+            }
+            total
+        })
+    }
+
+    fn perft_nodes(&mut self, depth: usize, table: &mut PerftTable<u64>) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        let mut moves = MoveList::new();
+        self.generate_legal_moves(self.white_turn, &mut moves);
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+        if let Some(nodes) = table.get(self.zobrist, depth) {
+            return nodes;
+        }
+
+        let mut nodes = 0;
+        for &m in moves.iter() {
+            self.make_move(m);
+            nodes += self.perft_nodes(depth - 1, table);
+            self.unmake_move();
+        }
+        table.insert(self.zobrist, depth, nodes);
+        nodes
+    }
+
+    fn perft_leaf_stats(&mut self, depth: usize, table: &mut PerftTable<PerftStats>) -> PerftStats {
+        let mut stats = PerftStats::default();
+        if depth == 0 {
+            stats.nodes = 1;
+            return stats;
+        }
+        if let Some(cached) = table.get(self.zobrist, depth) {
+            return cached;
+        }
+
+        let mut moves = MoveList::new();
+        self.generate_legal_moves(self.white_turn, &mut moves);
+        for &m in moves.iter() {
+            if depth == 1 {
+                self.classify_leaf(m, &mut stats);
+            } else {
+                self.make_move(m);
+                stats += self.perft_leaf_stats(depth - 1, table);
+                self.unmake_move();
+            }
+        }
+        table.insert(self.zobrist, depth, stats);
+        stats
+    }
+
+    fn classify_leaf(&mut self, mov: Move, stats: &mut PerftStats) {
+        stats.nodes += 1;
+        if mov.is_capture() {
+            stats.captures += 1;
+        }
+        if mov.is_en_passant() {
+            stats.en_passant += 1;
+        }
+        if mov.is_castle() {
+            stats.castles += 1;
+        }
+        if mov.promoted_to().is_some() {
+            stats.promotions += 1;
+        }
+
+        // Checks given by the moved piece, or the rook when castling, are direct.
+        // As in the published tables a check only counts as discovered if none of it is direct
+        let mut direct = if mov.is_castle() { Bitboard::EMPTY } else { mov.to().to_mask() };
+        if mov.is_castle() {
+            let kingside = mov.flag() == Move::KING_CASTLE;
+            let (_, _, rook_to) = Self::castling_tiles(self.castling, self.white_turn, kingside);
+            direct |= rook_to.to_mask();
+        }
+
+        self.make_move(mov);
+        let checkers = self.get_checkers(self.white_turn);
+        if checkers.some() {
+            stats.checks += 1;
+            if !(checkers & direct).some() {
+                stats.discovered_checks += 1;
+            }
+            if checkers.count_ones() > 1 {
+                stats.double_checks += 1;
+            }
+            let mut replies = MoveList::new();
+            self.generate_legal_moves(self.white_turn, &mut replies);
+            if replies.is_empty() {
+                stats.checkmates += 1;
+            }
+        }
+        self.unmake_move();
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{Bitboard, Board, Piece, Tile};
+
+/// Reasons a position cannot have been reached from the initial array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetroIssue {
+    /// More pieces than the original set allows, beyond what the missing pawns could promote to.
+    TooManyPromotions { white: bool, promoted: u32, missing_pawns: u32 },
+    /// A bishop walled off by unmoved pawns from its origin and from every promotion square.
+    UnreachableBishop(Tile),
+    /// The side to move is checked by pieces no single move can uncover together.
+    ImpossibleCheck,
+    /// The double push implied by the en passant square could not have been the last move.
+    ImpossibleEnPassant(Tile),
+}
+
+impl Board {
+    /// Look for signs the position was never reached in a game, for FENs from untrusted sources.
+    /// Structural problems are left to `validate`.
+    pub fn retro_analysis(&self) -> Result<(), Vec<RetroIssue>> {
+        let mut issues = Vec::new();
+        for white in [true, false] {
+            self.retro_material(white, &mut issues);
+        }
+
+        let kings = [&self.white, &self.black].map(|p| p.bb[Piece::King as usize].count_ones());
+        if kings == [1, 1] {
+            self.retro_checks(&mut issues);
+        }
+
+        if issues.is_empty() { Ok(()) } else { Err(issues) }
+    }
+
+    fn retro_material(&self, white: bool, issues: &mut Vec<RetroIssue>) {
+        let (player, _) = self.get_players(white);
+        let count = |piece: Piece| player.bb[piece as usize].count_ones();
+        let pawns = count(Piece::Pawn);
+
+        // Pawns still on their starting rank never moved, nothing gets past them
+        let walls = (self.white.bb[Piece::Pawn as usize] & Bitboard::RANK_2)
+            | (self.black.bb[Piece::Pawn as usize] & Bitboard::RANK_7);
+        let promotion_rank = if white { Bitboard::RANK_8 } else { Bitboard::RANK_1 };
+
+        let mut promoted = count(Piece::Queen).saturating_sub(1)
+            + count(Piece::Rook).saturating_sub(2)
+            + count(Piece::Knight).saturating_sub(2);
+
+        let origins = if white { [Tile::C1, Tile::F1] } else { [Tile::C8, Tile::F8] };
+        for origin in origins {
+            let colour = |t: &Tile| t.is_light_square() == origin.is_light_square();
+            let from_origin = bishop_reach(origin.to_mask(), walls);
+            let from_promotion = bishop_reach(promotion_rank, walls);
+
+            let mut original = 0;
+            for bishop in player.bb[Piece::Bishop as usize].iter().filter(colour) {
+                if from_origin.get_bit(bishop) {
+                    original += 1;
+                } else if from_promotion.get_bit(bishop) {
+                    promoted += 1;
+                } else {
+                    issues.push(RetroIssue::UnreachableBishop(bishop));
+                }
+            }
+            promoted += u32::saturating_sub(original, 1);
+        }
+
+        let missing_pawns = 8u32.saturating_sub(pawns);
+        if promoted > missing_pawns {
+            issues.push(RetroIssue::TooManyPromotions { white, promoted, missing_pawns });
+        }
+    }
+
+    fn retro_checks(&self, issues: &mut Vec<RetroIssue>) {
+        let white = self.white_turn;
+        let (player, opponent) = self.get_players(white);
+        let king = player.king_tile();
+        let checkers = self.get_checkers(white);
+
+        // A move checks with the piece that moved plus at most one slider it uncovered
+        let sliders = opponent.bb[Piece::Bishop as usize]
+            | opponent.bb[Piece::Rook as usize]
+            | opponent.bb[Piece::Queen as usize];
+        if checkers.count_ones() > 2 || (checkers.count_ones() == 2 && (checkers & sliders).none()) {
+            issues.push(RetroIssue::ImpossibleCheck);
+        }
+
+        let Some(ep) = self.en_passant else {
+            return;
+        };
+        let pushed = ep.backward(white);
+        let origin = ep.forward(white);
+        let possible = match (pushed, origin) {
+            (Some(pushed), Some(origin)) => {
+                self.get_piece_at_tile(pushed) == Some((Piece::Pawn, !white))
+                    && self.get_piece_at_tile(ep).is_none()
+                    && self.get_piece_at_tile(origin).is_none()
+                    // Any check must come from the pawn or through the square it left
+                    && checkers.iter().all(|c| c == pushed || c.get_between(king).get_bit(origin))
+            }
+            _ => false,
+        };
+        if !possible {
+            issues.push(RetroIssue::ImpossibleEnPassant(ep));
+        }
+    }
+}
+
+// Squares a bishop could walk to from `start` without crossing `walls`
+fn bishop_reach(start: Bitboard, walls: Bitboard) -> Bitboard {
+    let mut reach = start & !walls;
+    loop {
+        let next = reach
+            | (((reach << 9) & !Bitboard::FILE_A)
+                | ((reach << 7) & !Bitboard::FILE_H)
+                | ((reach >> 7) & !Bitboard::FILE_A)
+                | ((reach >> 9) & !Bitboard::FILE_H))
+                & !walls;
+        if next == reach {
+            return reach;
+        }
+        reach = next;
+    }
+}
@@ -0,0 +1,96 @@
+use crate::eval::PIECE_VALUES;
+use crate::{Bitboard, Board, Move, Piece, Tile};
+
+impl Board {
+    /// Static exchange evaluation, the material the side to move wins on the destination of `mov`
+    /// if both sides keep recapturing with their least valuable piece, and stop whenever it pays.
+    /// Pins are ignored, castling is always 0.
+    pub fn see(&self, mov: Move) -> i32 {
+        if mov.is_castle() {
+            return 0;
+        }
+        let (from, to) = (mov.from(), mov.to());
+        let Some((piece, white)) = self.get_piece_at_tile(from) else {
+            return 0;
+        };
+
+        let mut occupied = self.occupied();
+        occupied.set_bit(from, false);
+        let mut gains = Vec::with_capacity(32);
+        let mut gain = if mov.is_en_passant() {
+            occupied.set_bit(to.backward(white).unwrap(), false);
+            value(Piece::Pawn)
+        } else {
+            self.get_piece_at_tile(to).map_or(0, |(p, _)| value(p))
+        };
+        // Value of the piece standing on `to`, the next one to be captured
+        let mut victim = value(piece);
+        if let Some(promotion) = mov.promoted_to() {
+            gain += value(promotion) - value(Piece::Pawn);
+            victim = value(promotion);
+        }
+        gains.push(gain);
+
+        let mut attackers = self.attackers_to(to, occupied) & occupied;
+        let mut side = !white;
+        loop {
+            let (us, them) = self.get_players(side);
+            let ours = attackers & us.pieces;
+            let Some(attacker) = Piece::ALL_PIECES.into_iter().find(|&p| (ours & us.bb[p as usize]).some()) else {
+                break;
+            };
+            // The king can't capture into a defended tile
+            if attacker == Piece::King && (attackers & them.pieces).some() {
+                break;
+            }
+
+            gain = victim - gain;
+            gains.push(gain);
+            victim = value(attacker);
+
+            let tile = (ours & us.bb[attacker as usize]).iter().next().unwrap();
+            occupied.set_bit(tile, false);
+            // Sliders lined up behind the attacker join in
+            if matches!(attacker, Piece::Pawn | Piece::Bishop | Piece::Queen) {
+                attackers |= to.bishop_attacks(occupied) & self.pieces_of(Piece::Bishop, Piece::Queen);
+            }
+            if matches!(attacker, Piece::Rook | Piece::Queen) {
+                attackers |= to.rook_attacks(occupied) & self.pieces_of(Piece::Rook, Piece::Queen);
+            }
+            attackers &= occupied;
+            side = !side;
+        }
+
+        // Either side may decline to recapture, fold the swap list back to the first move
+        while gains.len() > 1 {
+            let last = gains.pop().unwrap();
+            let previous = gains.last_mut().unwrap();
+            *previous = -(-*previous).max(last);
+        }
+        gains[0]
+    }
+
+    /// Whether `see(mov)` is at least `threshold`.
+    pub fn see_ge(&self, mov: Move, threshold: i32) -> bool {
+        self.see(mov) >= threshold
+    }
+
+    // Pieces of both sides attacking `tile` through `occupied`
+    fn attackers_to(&self, tile: Tile, occupied: Bitboard) -> Bitboard {
+        (tile.pawn_attacks(true) & self.black.bb[Piece::Pawn as usize])
+            | (tile.pawn_attacks(false) & self.white.bb[Piece::Pawn as usize])
+            | (tile.knight_attacks() & self.pieces_of(Piece::Knight, Piece::Knight))
+            | (tile.king_attacks() & self.pieces_of(Piece::King, Piece::King))
+            | (tile.bishop_attacks(occupied) & self.pieces_of(Piece::Bishop, Piece::Queen))
+            | (tile.rook_attacks(occupied) & self.pieces_of(Piece::Rook, Piece::Queen))
+    }
+
+    fn pieces_of(&self, a: Piece, b: Piece) -> Bitboard {
+        self.white.bb[a as usize] | self.white.bb[b as usize] | self.black.bb[a as usize] | self.black.bb[b as usize]
+    }
+}
+
+fn value(piece: Piece) -> i32 {
+    // The king is never captured, any large value will do
+    if piece == Piece::King { 20_000 } else { PIECE_VALUES[piece as usize] }
+}
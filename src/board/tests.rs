@@ -1,13 +1,8 @@
-use crate::{Piece, Board, Tile};
+use crate::{CastlingRights, FenError, FenMode, MoveError, MoveResult, Piece, PositionError, RetroIssue, SanError, UciMoveError, Board, GenType, Move, MoveList, Tile, format_divide};
 
 fn empty_board_with(piece: Piece, tile: Tile, white: bool) -> Board {
     let mut board = Board::new_empty();
-    let player = if white {
-        &mut board.white
-    } else {
-        &mut board.black
-    };
-    player.place_piece(piece, tile);
+    board.place_piece(piece, white, tile);
     board
 }
 
@@ -53,9 +48,9 @@ fn king_attacks() {
 #[test]
 fn rook_attacks_with_blocker() {
     let mut board = Board::new_empty();
-    board.white.place_piece(Piece::Rook, Tile::D4);
-    board.white.place_piece(Piece::Pawn, Tile::D6);
-    board.black.place_piece(Piece::Pawn, Tile::F4);
+    board.place_piece(Piece::Rook, true, Tile::D4);
+    board.place_piece(Piece::Pawn, true, Tile::D6);
+    board.place_piece(Piece::Pawn, false, Tile::F4);
 
     let attacks = board.generate_attacks_from(Tile::D4);
     let occ = board.occupied();
@@ -67,8 +62,8 @@ fn rook_attacks_with_blocker() {
 #[test]
 fn mulitple_attacks() {
     let mut board = Board::new_empty();
-    board.white.place_piece(Piece::Knight, Tile::E4);
-    board.white.place_piece(Piece::Bishop, Tile::C1);
+    board.place_piece(Piece::Knight, true, Tile::E4);
+    board.place_piece(Piece::Bishop, true, Tile::C1);
 
     let attacks = board.generate_attacks(true);
 
@@ -80,8 +75,8 @@ fn mulitple_attacks() {
 #[test]
 fn king_danger() {
     let mut board = Board::new_empty();
-    board.white.place_piece(Piece::Queen, Tile::D1);
-    board.white.place_piece(Piece::King, Tile::E1); // should be ignored
+    board.place_piece(Piece::Queen, true, Tile::D1);
+    board.place_piece(Piece::King, true, Tile::E1); // should be ignored
 
     let attacks = board.generate_king_danger(false);
 
@@ -89,4 +84,660 @@ fn king_danger() {
         !attacks.get_bit(Tile::E1),
         "King danger should not include friendly king tile"
     );
-}
\ No newline at end of file
+}
+#[test]
+fn zobrist_incremental_matches_full() {
+    let mut board =
+        Board::new_from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+    let root_key = board.zobrist_key();
+
+    let mut moves = MoveList::new();
+    board.generate_legal_moves(board.white_turn, &mut moves);
+    for &m in moves.iter() {
+        board.make_move_unchecked(m);
+        assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+
+        let mut replies = MoveList::new();
+        board.generate_legal_moves(board.white_turn, &mut replies);
+        for &r in replies.iter() {
+            board.make_move_unchecked(r);
+            assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+            board.undo_move();
+        }
+
+        board.undo_move();
+        assert_eq!(board.zobrist_key(), root_key);
+    }
+}
+
+fn play(board: &mut Board, from: Tile, to: Tile) {
+    let mut moves = MoveList::new();
+    board.generate_legal_moves(board.white_turn, &mut moves);
+    let mov = *moves.iter().find(|m| m.from() == from && m.to() == to).unwrap();
+    board.make_move_unchecked(mov);
+}
+
+#[test]
+fn zobrist_transposition() {
+    let mut a = Board::new();
+    let mut b = Board::new();
+    for (from, to) in [(Tile::E2, Tile::E4), (Tile::E7, Tile::E5), (Tile::G1, Tile::F3)] {
+        play(&mut a, from, to);
+    }
+    for (from, to) in [(Tile::G1, Tile::F3), (Tile::E7, Tile::E5), (Tile::E2, Tile::E4)] {
+        play(&mut b, from, to);
+    }
+    assert_eq!(a.zobrist_key(), b.zobrist_key());
+}
+
+#[test]
+fn zobrist_after_field_edits() {
+    let mut board = Board::new();
+    board.set_castling(CastlingRights::WHITE_KINGSIDE);
+    board.set_white_turn(false);
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+    play(&mut board, Tile::D7, Tile::D5);
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+
+    // An en passant capture made possible by the edit
+    board.move_piece(Tile::E2, Tile::E5);
+    board.set_en_passant(Some(Tile::D6));
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+    play(&mut board, Tile::E5, Tile::D6);
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+
+    // Direct writes need a refresh
+    board.castling = CastlingRights::NONE;
+    board.white_turn = true;
+    board.refresh_zobrist();
+    play(&mut board, Tile::G1, Tile::F3);
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+}
+
+#[test]
+fn zobrist_castling_rook() {
+    // Same pieces, only the rook holding the queenside right differs
+    let a = Board::new_from_fen("4k3/8/8/8/8/8/8/RR2K3 w A - 0 1").unwrap();
+    let b = Board::new_from_fen("4k3/8/8/8/8/8/8/RR2K3 w B - 0 1").unwrap();
+    assert_ne!(a.castling, b.castling);
+    assert_ne!(a.zobrist_key(), b.zobrist_key());
+    assert_ne!(a.to_zobrist_hash(), b.to_zobrist_hash());
+}
+
+#[test]
+fn three_fold_repetition() {
+    let mut board = Board::new();
+    let shuffle = [(Tile::G1, Tile::F3), (Tile::G8, Tile::F6), (Tile::F3, Tile::G1), (Tile::F6, Tile::G8)];
+    for (i, &(from, to)) in shuffle.iter().chain(shuffle.iter()).enumerate() {
+        assert!(!board.three_fold_rep());
+        play(&mut board, from, to);
+        assert_eq!(board.zobrist_key() == Board::new().zobrist_key(), i % 4 == 3);
+    }
+    assert!(board.three_fold_rep());
+}
+
+#[test]
+fn make_unmake_restores_board() {
+    let mut board =
+        Board::new_from_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1")
+            .unwrap();
+    let original = board.clone();
+
+    let mut moves = MoveList::new();
+    board.generate_legal_moves(board.white_turn, &mut moves);
+    for &m in moves.iter() {
+        board.make_move(m);
+        assert!(board.history.is_empty());
+        board.unmake_move();
+        assert_eq!(board, original);
+    }
+}
+
+#[test]
+fn recorded_moves_keep_san() {
+    let mut board = Board::new();
+    play(&mut board, Tile::E2, Tile::E4);
+    play(&mut board, Tile::F7, Tile::F6);
+    play(&mut board, Tile::D1, Tile::H5);
+    assert_eq!(board.history.last().unwrap().1, "Qh5+");
+
+    board.undo_move();
+    assert_eq!(board.history.len(), 2);
+    assert_eq!(board.to_fen(), "rnbqkbnr/ppppp1pp/5p2/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2");
+}
+
+#[test]
+fn make_move_san_suffixes() {
+    let mut board = Board::new_from_fen("6k1/5ppp/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+    let mate = board.parse_uci_move("a1a8").unwrap();
+    assert_eq!(board.make_move_san(mate), "Ra8#");
+    assert!(board.history.is_empty());
+    board.unmake_move();
+    let check = board.parse_uci_move("a1a8").unwrap();
+    board.make_move(board.parse_uci_move("e1d1").unwrap());
+    board.make_move(board.parse_uci_move("h7h6").unwrap());
+    assert_eq!(board.make_move_san(check), "Ra8+");
+}
+
+#[test]
+fn undo_move_with_lean_moves_on_top() {
+    let mut board = Board::new();
+    play(&mut board, Tile::E2, Tile::E4);
+    let recorded = board.to_fen();
+    let mov = board.parse_uci_move("e7e5").unwrap();
+    board.make_move(mov);
+
+    // The lean move has no history entry to take back
+    assert!(!board.undo_move());
+    assert_eq!(board.history.len(), 1);
+    assert_eq!(board.undo_stack.len(), 2);
+
+    board.unmake_move();
+    assert_eq!(board.to_fen(), recorded);
+    assert!(board.undo_move());
+    assert!(board.history.is_empty());
+    assert!(!board.undo_move());
+
+    // Unmaking a recorded move drops its history entry too
+    play(&mut board, Tile::E2, Tile::E4);
+    board.unmake_move();
+    assert!(board.history.is_empty());
+}
+
+fn assert_mailbox_in_sync(board: &Board) {
+    for i in 0..64 {
+        let tile = Tile::new_index(i).unwrap();
+        let expected = match (board.white.get_piece(tile), board.black.get_piece(tile)) {
+            (Some(p), None) => Some((p, true)),
+            (None, Some(p)) => Some((p, false)),
+            (None, None) => None,
+            (Some(_), Some(_)) => panic!("Two pieces are overlapping"),
+        };
+        assert_eq!(board.get_piece_at_tile(tile), expected, "mailbox out of sync on {}", tile);
+    }
+}
+
+#[test]
+fn mailbox_follows_make_unmake() {
+    let mut board =
+        Board::new_from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+    assert_mailbox_in_sync(&board);
+
+    let mut moves = MoveList::new();
+    board.generate_legal_moves(board.white_turn, &mut moves);
+    for &m in moves.iter() {
+        board.make_move(m);
+        assert_mailbox_in_sync(&board);
+
+        let mut replies = MoveList::new();
+        board.generate_legal_moves(board.white_turn, &mut replies);
+        for &r in replies.iter() {
+            board.make_move(r);
+            assert_mailbox_in_sync(&board);
+            board.unmake_move();
+        }
+
+        board.unmake_move();
+        assert_mailbox_in_sync(&board);
+    }
+}
+
+#[test]
+fn editing_keeps_mailbox_in_sync() {
+    let mut board = Board::new_empty();
+    board.place_piece(Piece::Queen, true, Tile::D4);
+    assert_eq!(board.get_piece_at_tile(Tile::D4), Some((Piece::Queen, true)));
+    assert_mailbox_in_sync(&board);
+
+    board.move_piece(Tile::D4, Tile::H8);
+    assert_eq!(board.get_piece_at_tile(Tile::D4), None);
+    assert_eq!(board.get_piece_at_tile(Tile::H8), Some((Piece::Queen, true)));
+    assert_mailbox_in_sync(&board);
+
+    assert_eq!(board.remove_piece(Tile::H8), Some((Piece::Queen, true)));
+    assert_eq!(board.remove_piece(Tile::H8), None);
+    assert!(board.white().pieces.none());
+    assert_mailbox_in_sync(&board);
+
+    // Landing on an occupied tile replaces the piece there
+    let mut board = Board::new();
+    board.move_piece(Tile::D1, Tile::D7);
+    board.place_piece(Piece::Knight, true, Tile::E7);
+    assert_eq!(board.get_piece_at_tile(Tile::D7), Some((Piece::Queen, true)));
+    assert!(!board.black().pieces.get_bit(Tile::D7) && !board.black().pieces.get_bit(Tile::E7));
+    assert_mailbox_in_sync(&board);
+
+    board.edit_players(|white, black| {
+        white.remove_piece(Tile::E7);
+        black.place_piece(Piece::Queen, Tile::E4);
+    });
+    assert_eq!(board.get_piece_at_tile(Tile::E7), None);
+    assert_eq!(board.get_piece_at_tile(Tile::E4), Some((Piece::Queen, false)));
+    assert_mailbox_in_sync(&board);
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+}
+
+#[test]
+fn editing_keeps_zobrist_in_sync() {
+    let mut board = Board::new();
+    board.remove_piece(Tile::B1);
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+    play(&mut board, Tile::E2, Tile::E4);
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+
+    // A pawn placed next to the double pushed one makes en passant possible
+    board.place_piece(Piece::Pawn, false, Tile::D4);
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+    board.move_piece(Tile::G8, Tile::F6);
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+    play(&mut board, Tile::D4, Tile::E3);
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+    board.undo_move();
+    assert_eq!(board.zobrist_key(), board.to_zobrist_hash());
+}
+
+#[test]
+fn pinned_pieces_xray() {
+    // Rook pinned on the file, bishop pinned on the diagonal, knight shielded by a second piece
+    let board = Board::new_from_fen("4k3/8/8/b7/4r2q/6P1/3BRN2/4K3 w - - 0 1").unwrap();
+    let pinned = board.get_pinned_pieces(true);
+    assert_eq!(pinned, Tile::E2.to_mask() | Tile::D2.to_mask());
+
+    let mut moves = MoveList::new();
+    board.generate_legal_moves(true, &mut moves);
+    for m in moves.iter().filter(|m| m.from() == Tile::E2) {
+        assert_eq!(m.to().get_coords().0, 4, "pinned rook left the file");
+    }
+    let bishop: Vec<Tile> = moves.iter().filter(|m| m.from() == Tile::D2).map(|m| m.to()).collect();
+    assert_eq!(bishop, vec![Tile::C3, Tile::B4, Tile::A5]);
+}
+
+#[test]
+fn quiet_checks_include_castling_and_discoveries() {
+    // Castling puts the rook on the f-file
+    let board = Board::new_from_fen("5k2/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+    let mut checks = MoveList::new();
+    board.generate_moves(true, GenType::QuietChecks, &mut checks);
+    assert!(checks.iter().any(|m| m.from() == Tile::E1 && m.to() == Tile::G1));
+
+    // Every bishop move uncovers the queen's file
+    let board = Board::new_from_fen("3k4/8/8/8/8/8/3B4/3QK3 w - - 0 1").unwrap();
+    let mut checks = MoveList::new();
+    board.generate_moves(true, GenType::QuietChecks, &mut checks);
+    let bishop = checks.iter().filter(|m| m.from() == Tile::D2).count();
+    assert_eq!(bishop, 8);
+    assert!(checks.iter().all(|m| board.gives_check(m)));
+}
+
+#[test]
+fn moves_compare_across_positions() {
+    // The same move is equal whatever state surrounds it
+    let mut board = Board::new();
+    let mut before = MoveList::new();
+    board.generate_legal_moves(true, &mut before);
+    board.is_in_check(true);
+    board.set_castling(CastlingRights::NONE);
+    let mut after = MoveList::new();
+    board.generate_legal_moves(true, &mut after);
+    for m in before.iter() {
+        assert!(after.contains(m));
+    }
+    assert_eq!(std::mem::size_of::<Move>(), 2);
+}
+
+#[test]
+fn decode_recovers_pieces() {
+    let mut board = Board::new_from_fen("4k3/3p4/8/4P3/8/8/8/4K2R b K - 0 1").unwrap();
+    play(&mut board, Tile::D7, Tile::D5);
+    let mut moves = MoveList::new();
+    board.generate_legal_moves(true, &mut moves);
+    let ep = *moves.iter().find(|m| m.from() == Tile::E5 && m.to() == Tile::D6).unwrap();
+
+    let decoded = board.decode(ep.to_u16()).unwrap();
+    assert_eq!((decoded.piece, decoded.capture), (Piece::Pawn, Some(Piece::Pawn)));
+    assert!(ep.is_en_passant());
+
+    let castle = board.decode(Move::new(Tile::E1, Tile::G1, Move::KING_CASTLE).to_u16()).unwrap();
+    assert_eq!((castle.piece, castle.capture), (Piece::King, None));
+
+    // Nothing of ours on the from tile
+    assert_eq!(board.decode(Move::new(Tile::D5, Tile::D4, Move::QUIET).to_u16()), None);
+}
+
+#[test]
+fn divide_in_stockfish_format() {
+    let mut board = Board::new();
+    let divide = board.positions_divide(2);
+    assert_eq!(divide.len(), 20);
+
+    let text = format_divide(&divide);
+    assert!(text.lines().any(|l| l == "e2e4: 20"));
+    assert!(text.lines().any(|l| l == "g1f3: 20"));
+    assert!(text.ends_with("\n\nNodes searched: 400\n"));
+
+    let mut board = Board::new_from_fen("8/4P1k1/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let divide = board.positions_divide(1);
+    let promotions: Vec<String> = divide.iter().filter(|(m, _)| m.from() == Tile::E7).map(|(m, _)| m.to_uci()).collect();
+    assert_eq!(promotions, ["e7e8n", "e7e8b", "e7e8r", "e7e8q"]);
+}
+
+#[test]
+fn chess960_start_positions() {
+    assert_eq!(Board::new_chess960(518).unwrap().to_fen(), Board::new().to_fen());
+    let board = Board::new_chess960(0).unwrap();
+    assert_eq!(board.to_fen(), "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w KQkq - 0 1");
+    assert_eq!(board.to_shredder_fen(), "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w HFhf - 0 1");
+    assert!(board.chess960);
+    assert_eq!(Board::new_chess960(959).unwrap().to_fen(), "rkrnnqbb/pppppppp/8/8/8/8/PPPPPPPP/RKRNNQBB w KQkq - 0 1");
+    assert!(Board::new_chess960(960).is_none());
+}
+
+#[test]
+fn chess960_castling_by_tiles() {
+    // The king clicks onto its own rook
+    let mut board = Board::new_from_fen("4k3/8/8/8/8/8/8/5KR1 w G - 0 1").unwrap();
+    assert!(matches!(board.try_move_piece(Tile::F1, Tile::G1, None), Ok(MoveResult::MoveApplied(_))));
+    assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/5RK1 b - - 1 1");
+    assert_eq!(board.history.last().unwrap().1, "O-O");
+
+    // Not while the king's path is attacked
+    let mut board = Board::new_from_fen("4kr2/8/8/8/8/8/8/4K1R1 w G - 0 1").unwrap();
+    assert_eq!(board.try_move_piece(Tile::E1, Tile::G1, None), Err(MoveError::IllegalMove));
+    // Other rooks are still a friendly capture
+    let mut board = Board::new_from_fen("4k3/8/8/8/8/8/8/R4KR1 w G - 0 1").unwrap();
+    assert_eq!(board.try_move_piece(Tile::F1, Tile::A1, None), Err(MoveError::FriendlyCapture));
+}
+
+#[test]
+fn chess960_castling_fen() {
+    // Shredder letters name the rook, X-FEN only needs them when another rook is further out
+    let board = Board::new_from_fen("1r2k1r1/8/8/8/8/8/8/R1R1K2R w CHg - 0 1").unwrap();
+    assert!(board.chess960);
+    assert_eq!(board.castling.rook_tile(CastlingRights::WHITE_QUEENSIDE), Tile::C1);
+    assert_eq!(board.castling.rook_tile(CastlingRights::WHITE_KINGSIDE), Tile::H1);
+    assert_eq!(board.castling.rook_tile(CastlingRights::BLACK_KINGSIDE), Tile::G8);
+    assert_eq!(board.to_fen(), "1r2k1r1/8/8/8/8/8/8/R1R1K2R w KCk - 0 1");
+    assert_eq!(board.to_shredder_fen(), "1r2k1r1/8/8/8/8/8/8/R1R1K2R w HCg - 0 1");
+    assert_eq!(Board::new_from_fen(&board.to_fen()).unwrap().castling, board.castling);
+
+    assert!(!Board::new().chess960);
+    assert_eq!(Board::new_from_fen("r3k2r/8/8/8/8/8/8/R3K2R w HAha - 0 1").unwrap().castling, CastlingRights::ALL);
+}
+
+#[test]
+fn chess960_king_takes_rook() {
+    // King already on g1, castling only moves the rook
+    let mut board = Board::new_from_fen("4k3/8/8/8/8/8/8/6KR w H - 0 1").unwrap();
+    let before = board.clone();
+
+    let castle = board.castling_move(true, true).unwrap();
+    assert_eq!((castle.from(), castle.to()), (Tile::G1, Tile::H1));
+    assert_eq!(castle.to_uci(), "g1h1");
+    let mut moves = MoveList::new();
+    board.generate_legal_moves(true, &mut moves);
+    assert!(moves.contains(&castle));
+    assert_eq!(board.move_to_san(&castle), "O-O");
+
+    board.make_move(castle);
+    assert_eq!(board.get_piece_at_tile(Tile::G1), Some((Piece::King, true)));
+    assert_eq!(board.get_piece_at_tile(Tile::F1), Some((Piece::Rook, true)));
+    assert_eq!(board.get_piece_at_tile(Tile::H1), None);
+    board.unmake_move();
+    assert_eq!(board, before);
+
+    // Rook on b1 shields c1 from the queen on a1
+    let board = Board::new_from_fen("4k3/8/8/8/8/8/8/qR1K4 w B - 0 1").unwrap();
+    assert_eq!(board.castling_move(true, false).map(|m| m.to()), Some(Tile::B1));
+    let mut moves = MoveList::new();
+    board.generate_legal_moves(true, &mut moves);
+    assert!(!moves.iter().any(|m| m.is_castle()));
+}
+
+#[test]
+fn fen_round_trip() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "rnbqkbnr/pp1ppppp/8/2p5/4P3/8/PPPP1PPP/RNBQKBNR w KQkq c6 0 2",
+        "rnbqkbnr/pppp1ppp/8/8/3Pp3/8/PPP1PPPP/RNBQKBNR b KQkq d3 0 3",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    ] {
+        assert_eq!(Board::new_from_fen(fen).unwrap().to_fen(), fen);
+        assert_eq!(Board::new_from_fen_with(fen, FenMode::Lenient).unwrap().to_fen(), fen);
+    }
+    let board = Board::new_from_fen("rnbqkbnr/pp1ppppp/8/2p5/4P3/8/PPPP1PPP/RNBQKBNR w KQkq c6 0 2").unwrap();
+    assert_eq!(board.en_passant, Some(Tile::C6));
+}
+
+#[test]
+fn fen_strict_errors() {
+    let invalid = |field: usize, text: &str| Err(FenError::InvalidField { field, text: text.to_string() });
+    let parse = |fen: &str| Board::new_from_fen_with(fen, FenMode::Strict).map(|b| b.to_fen());
+
+    assert_eq!(parse("8/8/8/8/8/8/8 w - - 0 1"), invalid(0, "8/8/8/8/8/8/8"));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K2X w - - 0 1"), invalid(0, "4K2X"));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K4 w - - 0 1"), invalid(0, "4K4"));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 x - - 0 1"), invalid(1, "x"));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K2R w KX - 0 1"), invalid(2, "KX"));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w K - 0 1"), invalid(2, "K"));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - e4 0 1"), invalid(3, "e4"));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - - x 1"), invalid(4, "x"));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - - 0 0"), invalid(5, "0"));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - -"), Err(FenError::MissingField { field: 4 }));
+    assert_eq!(
+        parse("4k3/8/8/8/8/8/8/4K3 w - - 0 1 extra"),
+        Err(FenError::ExtraField { field: 6, text: "extra".to_string() })
+    );
+    assert_eq!(parse(""), Err(FenError::MissingField { field: 0 }));
+    assert_eq!(
+        FenError::InvalidField { field: 1, text: "x".to_string() }.to_string(),
+        "Invalid active color in FEN: 'x'"
+    );
+}
+
+#[test]
+fn fen_optional_clocks() {
+    let parse = |fen: &str| Board::new_from_fen(fen).map(|b| b.to_fen());
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - -"), Ok("4k3/8/8/8/8/8/8/4K3 w - - 0 1".to_string()));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - - 7"), Ok("4k3/8/8/8/8/8/8/4K3 w - - 7 1".to_string()));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - - 0 1 extra"), Ok("4k3/8/8/8/8/8/8/4K3 w - - 0 1".to_string()));
+    // Clocks past what fits in a byte are still legal
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - - 300 200"), Ok("4k3/8/8/8/8/8/8/4K3 w - - 300 200".to_string()));
+    // The other fields are as strict as ever
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w -"), Err(FenError::MissingField { field: 3 }));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 x - - 0 1"), Err(FenError::InvalidField { field: 1, text: "x".to_string() }));
+    assert_eq!(parse("4k3/8/8/8/8/8/8/4K3 w - - x 1"), Err(FenError::InvalidField { field: 4, text: "x".to_string() }));
+}
+
+#[test]
+fn fen_lenient_defaults() {
+    let lenient = |fen: &str| Board::new_from_fen_with(fen, FenMode::Lenient).map(|b| b.to_fen());
+    assert_eq!(lenient("4k3/8/8/8/8/8/8/4K3"), Ok("4k3/8/8/8/8/8/8/4K3 w - - 0 1".to_string()));
+    assert_eq!(lenient("4k3/8/8/8/8/8/8/4K2R b KX e4 x 0"), Ok("4k3/8/8/8/8/8/8/4K2R b K - 0 1".to_string()));
+    // The placement is never guessed
+    assert!(lenient("4k3/8/8/8/8/8/8/4K4").is_err());
+}
+
+#[test]
+fn validate_positions() {
+    let errors = |fen: &str| Board::new_from_fen(fen).unwrap().validate().err().unwrap_or_default();
+
+    assert_eq!(Board::new().validate(), Ok(()));
+    assert_eq!(errors("rnbqkbnr/pppp1ppp/8/8/3Pp3/8/PPP1PPPP/RNBQKBNR b KQkq d3 0 3"), vec![]);
+    assert_eq!(errors("8/8/8/8/8/8/8/4K3 w - - 0 1"), vec![PositionError::MissingKing(false)]);
+    assert_eq!(errors("4k3/8/8/8/8/8/8/K3K3 w - - 0 1"), vec![PositionError::TooManyKings(true)]);
+    assert_eq!(
+        errors("4k2P/8/8/8/8/8/8/p3K3 w - - 0 1"),
+        vec![PositionError::PawnOnBackRank(Tile::A1), PositionError::PawnOnBackRank(Tile::H8)]
+    );
+    assert_eq!(errors("4k3/8/8/8/8/8/8/4K2R w - - 0 1"), vec![]);
+    assert_eq!(errors("4k3/8/8/8/8/8/8/4R1K1 w - - 0 1"), vec![PositionError::OpponentInCheck]);
+    assert_eq!(errors("4k3/8/8/8/8/8/8/4K3 w - e6 0 1"), vec![PositionError::InvalidEnPassant(Tile::E6)]);
+    assert_eq!(errors("4k3/8/8/4p3/8/8/8/4K3 w - e6 0 1"), vec![]);
+    assert_eq!(errors("4k3/8/8/8/8/8/8/R4K1R w A - 0 1"), vec![]);
+
+    // Castling rights whose rook has since been replaced by another piece
+    let mut board = Board::new_from_fen("4k3/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+    board.remove_piece(Tile::H1);
+    board.place_piece(Piece::Knight, true, Tile::H1);
+    assert_eq!(board.validate(), Err(vec![PositionError::InvalidCastling(CastlingRights::WHITE_KINGSIDE)]));
+
+    assert_eq!(
+        Board::new_from_fen_validated("8/8/8/8/8/8/8/4K3 w - - 0 1").unwrap_err(),
+        FenError::IllegalPosition(vec![PositionError::MissingKing(false)])
+    );
+}
+
+#[test]
+fn retro_analysis() {
+    let issues = |fen: &str| Board::new_from_fen(fen).unwrap().retro_analysis().err().unwrap_or_default();
+
+    assert_eq!(Board::new().retro_analysis(), Ok(()));
+    assert_eq!(issues("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1"), vec![]);
+    assert_eq!(issues("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1"), vec![]);
+
+    // A second queen with every pawn still on the board
+    assert_eq!(
+        issues("4k3/8/8/8/8/8/PPPPPPPP/QQ2K3 w - - 0 1"),
+        vec![RetroIssue::TooManyPromotions { white: true, promoted: 1, missing_pawns: 0 }]
+    );
+    // The c1 bishop could never get past b2 and d2, so this one is promoted
+    assert_eq!(
+        issues("4k3/8/8/4B3/8/8/PPPPPPPP/4K3 w - - 0 1"),
+        vec![RetroIssue::TooManyPromotions { white: true, promoted: 1, missing_pawns: 0 }]
+    );
+    assert_eq!(issues("4k3/8/8/4B3/8/8/PPPPPPP1/4K3 w - - 0 1"), vec![]);
+    // Nothing reaches a1 with a pawn still on b2
+    assert_eq!(issues("4k3/8/8/8/8/8/1P6/B3K3 w - - 0 1"), vec![RetroIssue::UnreachableBishop(Tile::A1)]);
+
+    assert_eq!(issues("4k3/8/3N1N2/8/8/8/8/4K3 b - - 0 1"), vec![RetroIssue::ImpossibleCheck]);
+    assert_eq!(issues("4k3/8/3N4/8/8/8/8/4R1K1 b - - 0 1"), vec![]);
+
+    assert_eq!(issues("6k1/8/8/3pP3/8/8/8/4K3 w - d6 0 1"), vec![]);
+    assert_eq!(issues("6k1/8/8/3pP3/8/8/8/r3K3 w - d6 0 1"), vec![RetroIssue::ImpossibleEnPassant(Tile::D6)]);
+    // The double push uncovered the bishop
+    assert_eq!(issues("2b3k1/8/8/3pP3/8/7K/8/8 w - d6 0 1"), vec![]);
+}
+
+#[test]
+fn parse_uci_moves() {
+    let board = Board::new_from_fen("r3k2r/1P6/8/3pP3/8/8/8/R3K2R w KQkq d6 0 1").unwrap();
+    let parse = |s: &str| board.parse_uci_move(s);
+
+    assert_eq!(parse("e5d6").unwrap(), Move::new(Tile::E5, Tile::D6, Move::EN_PASSANT));
+    assert_eq!(parse("b7a8n").unwrap(), Move::new_promotion(Tile::B7, Tile::A8, Piece::Knight, true));
+    assert_eq!(parse("b7b8q").unwrap(), Move::new_promotion(Tile::B7, Tile::B8, Piece::Queen, false));
+    assert_eq!(parse("e1g1").unwrap(), Move::new(Tile::E1, Tile::G1, Move::KING_CASTLE));
+    // King takes rook is accepted for castling too
+    assert_eq!(parse("e1a1").unwrap(), Move::new(Tile::E1, Tile::C1, Move::QUEEN_CASTLE));
+
+    assert_eq!(parse("b7b8"), Err(UciMoveError::Illegal("b7b8".to_string())));
+    assert_eq!(parse("e1e3"), Err(UciMoveError::Illegal("e1e3".to_string())));
+    for bad in ["", "e2", "e2e9", "e7e8k", "e2e4e5", "0000"] {
+        assert_eq!(parse(bad), Err(UciMoveError::Unparseable(bad.to_string())));
+    }
+
+    for mov in [parse("e5d6").unwrap(), parse("b7a8n").unwrap(), parse("e1g1").unwrap()] {
+        assert_eq!(parse(&mov.to_string()), Ok(mov));
+    }
+    assert_eq!(parse("b7a8n").unwrap().to_string(), "b7a8n");
+
+    // Chess960 castling is written king takes rook, but the destination also works
+    let mut board = Board::new_from_fen("4k3/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+    board.chess960 = true;
+    let castle = board.castling_move(true, true).unwrap();
+    assert_eq!(castle.to_string(), "e1h1");
+    assert_eq!(board.parse_uci_move("e1h1"), Ok(castle));
+    assert_eq!(board.parse_uci_move("e1g1"), Ok(castle));
+}
+
+#[test]
+fn parse_san() {
+    let board = Board::new();
+    assert_eq!(board.parse_san("e4").unwrap().to_uci(), "e2e4");
+    assert_eq!(board.parse_san("Nf3").unwrap().to_uci(), "g1f3");
+    for variant in ["Nf3+", "Nf3!?", "Ng1f3", "Ng1-f3", "nf3", "Nf3 ?!"] {
+        assert_eq!(board.parse_san(variant).unwrap().to_uci(), "g1f3", "{}", variant);
+    }
+    assert_eq!(board.parse_san("e2-e4").unwrap().to_uci(), "e2e4");
+    assert_eq!(board.parse_san("e5"), Err(SanError::Illegal));
+    assert_eq!(board.parse_san("Bf3"), Err(SanError::WrongPiece));
+    assert_eq!(board.parse_san("Qe5"), Err(SanError::Illegal));
+    for junk in ["", "Z4", "Nz9", "e", "Nf3f3f3", "e9"] {
+        assert_eq!(board.parse_san(junk), Err(SanError::Unparseable), "{}", junk);
+    }
+
+    // The knight on g3 is pinned, so only the c3 knight reaches e4
+    let pinned = Board::new_from_fen("4k3/8/8/8/7b/2N3N1/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(pinned.parse_san("Ne4").unwrap().to_uci(), "c3e4");
+    let board = Board::new_from_fen("4k3/8/8/8/8/2N3N1/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(board.parse_san("Ne4"), Err(SanError::Ambiguous));
+    assert_eq!(board.parse_san("Nge4").unwrap().to_uci(), "g3e4");
+    let board = Board::new_from_fen("4k3/8/8/2N5/8/2N5/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(board.parse_san("N3e4").unwrap().to_uci(), "c3e4");
+
+    let board = Board::new_from_fen("4k3/8/8/8/4B3/8/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(board.parse_san("Bc2").unwrap().to_uci(), "e4c2");
+    // Lowercase bishops, and a b-pawn taking first when both read
+    assert_eq!(board.parse_san("bc2").unwrap().to_uci(), "e4c2");
+    let board = Board::new_from_fen("4k3/8/8/8/8/2p5/1P1B4/4K3 w - - 0 1").unwrap();
+    assert_eq!(board.parse_san("bxc3").unwrap().to_uci(), "b2c3");
+    assert_eq!(board.parse_san("Bxc3").unwrap().to_uci(), "d2c3");
+
+    let board = Board::new_from_fen("4k3/1P6/8/3pP3/8/8/8/R3K2R w KQ d6 0 1").unwrap();
+    assert_eq!(board.parse_san("exd6 e.p.").unwrap(), Move::new(Tile::E5, Tile::D6, Move::EN_PASSANT));
+    assert_eq!(board.parse_san("exd6e.p.").unwrap(), Move::new(Tile::E5, Tile::D6, Move::EN_PASSANT));
+    assert_eq!(board.parse_san("exd6ep").unwrap(), Move::new(Tile::E5, Tile::D6, Move::EN_PASSANT));
+    assert_eq!(board.parse_san("ed6").unwrap(), Move::new(Tile::E5, Tile::D6, Move::EN_PASSANT));
+    for promotion in ["b8=Q", "b8Q", "b8q", "b8=Q+", "b8/Q"] {
+        assert_eq!(board.parse_san(promotion).unwrap().to_uci(), "b7b8q", "{}", promotion);
+    }
+    assert_eq!(board.parse_san("b8"), Err(SanError::Illegal));
+    assert_eq!(board.parse_san("b8=K"), Err(SanError::Unparseable));
+    for castle in ["O-O", "0-0", "o-o", "O-O+"] {
+        assert_eq!(board.parse_san(castle).unwrap().to_uci(), "e1g1", "{}", castle);
+    }
+    assert_eq!(board.parse_san("O-O-O").unwrap().to_uci(), "e1c1");
+
+    // Castling through check is refused
+    let board = Board::new_from_fen("4k3/8/8/8/8/8/5r2/R3K2R w KQ - 0 1").unwrap();
+    assert_eq!(board.parse_san("O-O"), Err(SanError::Illegal));
+    assert_eq!(board.move_from_algebraic("O-O"), None);
+}
+
+#[test]
+fn static_exchange() {
+    let see = |fen: &str, uci: &str| {
+        let board = Board::new_from_fen(fen).unwrap();
+        board.see(board.parse_uci_move(uci).unwrap())
+    };
+    // Undefended pawn
+    assert_eq!(see("1k1r4/1pp4p/p7/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1", "e1e5"), 100);
+    // Defended twice, with the queen x-raying through the bishop
+    assert_eq!(see("1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1", "d3e5"), -220);
+
+    // The rook behind wins the exchange back
+    assert_eq!(see("3rk3/8/8/3p4/8/8/3R4/3RK3 w - - 0 1", "d2d5"), 100);
+    assert_eq!(see("3rk3/8/8/3p4/8/8/3R4/4K3 w - - 0 1", "d2d5"), -400);
+
+    // Quiet moves into an attack, en passant, castling and promotions
+    assert_eq!(see("4k3/8/8/2p5/8/8/8/3QK3 w - - 0 1", "d1d4"), -900);
+    assert_eq!(see("4k3/8/8/2p5/8/8/8/3QK3 w - - 0 1", "d1d3"), 0);
+    assert_eq!(see("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6"), 100);
+    assert_eq!(see("4k3/8/8/8/8/8/8/4K2R w K - 0 1", "e1g1"), 0);
+    assert_eq!(see("8/P7/8/8/8/8/8/k6K w - - 0 1", "a7a8q"), 800);
+    assert_eq!(see("1r6/P7/8/8/8/8/8/k6K w - - 0 1", "a7a8q"), -100);
+    assert_eq!(see("1r6/P7/8/8/8/8/8/k6K w - - 0 1", "a7b8q"), 1300);
+
+    // The king only recaptures when nothing else defends
+    assert_eq!(see("4k3/4r3/8/8/8/8/4P3/4K3 b - - 0 1", "e7e2"), -400);
+    assert_eq!(see("4q1k1/4r3/8/8/8/8/4P3/4K3 b - - 0 1", "e7e2"), 100);
+
+    let board = Board::new_from_fen("3rk3/8/8/3p4/8/8/3R4/4K3 w - - 0 1").unwrap();
+    let mov = board.parse_uci_move("d2d5").unwrap();
+    assert!(board.see_ge(mov, -400));
+    assert!(!board.see_ge(mov, 0));
+}
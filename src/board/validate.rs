@@ -0,0 +1,78 @@
+use crate::{Bitboard, Board, CastlingRights, FenError, FenMode, Piece, PositionError};
+
+impl Board {
+    /// Check the position can be handed to move generation: one king each, no pawns on the
+    /// back ranks, the side not to move not in check and en passant / castling matching the pieces.
+    pub fn validate(&self) -> Result<(), Vec<PositionError>> {
+        let mut errors = Vec::new();
+
+        for white in [true, false] {
+            let (player, _) = self.get_players(white);
+            match player.bb[Piece::King as usize].count_ones() {
+                0 => errors.push(PositionError::MissingKing(white)),
+                1 => {}
+                _ => errors.push(PositionError::TooManyKings(white)),
+            }
+            if player.bb[Piece::Pawn as usize].count_ones() > 8 {
+                errors.push(PositionError::TooManyPawns(white));
+            }
+            if player.pieces.count_ones() > 16 {
+                errors.push(PositionError::TooManyPieces(white));
+            }
+        }
+
+        let pawns = self.white.bb[Piece::Pawn as usize] | self.black.bb[Piece::Pawn as usize];
+        for tile in pawns & (Bitboard::RANK_1 | Bitboard::RANK_8) {
+            errors.push(PositionError::PawnOnBackRank(tile));
+        }
+
+        // Checks only make sense with exactly one king each
+        let kings_ok = !errors.iter().any(|e| matches!(e, PositionError::MissingKing(_) | PositionError::TooManyKings(_)));
+        if kings_ok {
+            let (_, opponent) = self.current_players();
+            if self.tile_attacked(opponent.king_tile(), self.white_turn) {
+                errors.push(PositionError::OpponentInCheck);
+            }
+        }
+
+        if let Some(ep) = self.en_passant {
+            // The pawn that just moved two squares sits in front of the square, which it passed over
+            let white = self.white_turn;
+            let valid = ep.get_coords().1 == if white { 5 } else { 2 }
+                && self.get_piece_at_tile(ep).is_none()
+                && ep.forward(white).is_some_and(|t| self.get_piece_at_tile(t).is_none())
+                && ep.backward(white).is_some_and(|t| self.get_piece_at_tile(t) == Some((Piece::Pawn, !white)));
+            if !valid {
+                errors.push(PositionError::InvalidEnPassant(ep));
+            }
+        }
+
+        for right in CastlingRights::EACH {
+            if !self.castling.contains(right) {
+                continue;
+            }
+            let white = right.contains(CastlingRights::WHITE_KINGSIDE | CastlingRights::WHITE_QUEENSIDE);
+            let kingside = right.contains(CastlingRights::WHITE_KINGSIDE | CastlingRights::BLACK_KINGSIDE);
+            let (player, _) = self.get_players(white);
+            let rank = if white { 0 } else { 7 };
+            let rook = self.castling.rook_tile(right);
+
+            let king_ok = player.bb[Piece::King as usize].to_bit().is_some_and(|king| {
+                let (file, king_rank) = king.get_coords();
+                king_rank == rank && (rook.get_coords().0 > file) == kingside
+            });
+            if !king_ok || self.get_piece_at_tile(rook) != Some((Piece::Rook, white)) {
+                errors.push(PositionError::InvalidCastling(right));
+            }
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// Parse a FEN strictly and reject it if `validate` finds problems, for user input.
+    pub fn new_from_fen_validated(fen: &str) -> Result<Self, FenError> {
+        let board = Self::new_from_fen_with(fen, FenMode::Strict)?;
+        board.validate().map_err(FenError::IllegalPosition)?;
+        Ok(board)
+    }
+}
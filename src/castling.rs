@@ -1,16 +1,34 @@
 use std::ops::BitOr;
 
+use crate::Tile;
+
 mod constants;
 #[cfg(test)]
 mod tests;
 
+// 0 - 3    ->  Rights      ( WK, WQ, BK, BQ )
+// 4 - 15   ->  Rook files  ( 3 bits per right, same order )
+// Files of rights that are not held stay at their defaults so equal rights compare equal
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct CastlingRights(u8);
+pub struct CastlingRights(u16);
 
 impl CastlingRights {
+    const FILE_SHIFT: u8 = 4;
+    // H, A, H, A
+    const DEFAULT_FILES: u16 = 7 | (7 << 6);
+
     // Constructors
     pub const fn new(rights: u8) -> Self {
-        CastlingRights(rights)
+        CastlingRights((rights & 0xF) as u16 | (Self::DEFAULT_FILES << Self::FILE_SHIFT))
+    }
+    /// The single right for one side of one player.
+    pub const fn side(white: bool, kingside: bool) -> Self {
+        match (white, kingside) {
+            (true, true) => Self::WHITE_KINGSIDE,
+            (true, false) => Self::WHITE_QUEENSIDE,
+            (false, true) => Self::BLACK_KINGSIDE,
+            (false, false) => Self::BLACK_QUEENSIDE,
+        }
     }
     pub fn from_fen(fen: &str) -> Self {
         let mut rights = CastlingRights::NONE;
@@ -28,15 +46,42 @@ impl CastlingRights {
     }
 
     pub fn contains(&self, rights: CastlingRights) -> bool {
-        self.0 & rights.0 != 0
+        self.0 & rights.0 & 0xF != 0
+    }
+    /// File of the rook a single right castles with, h or a unless set otherwise for Chess960.
+    pub fn rook_file(&self, right: CastlingRights) -> u8 {
+        let shift = Self::FILE_SHIFT + 3 * right.index();
+        ((self.0 >> shift) & 0b111) as u8
+    }
+    pub fn rook_tile(&self, right: CastlingRights) -> Tile {
+        let rank = if right.0 & 0b0011 != 0 { 0 } else { 7 };
+        Tile::new_xy(self.rook_file(right), rank).unwrap()
     }
 
     // Modifications
     pub fn remove(&mut self, rights: CastlingRights) {
-        self.0 &= !rights.0;
+        for right in Self::EACH {
+            if rights.contains(right) {
+                self.set_file(right.index(), CastlingRights::NONE.rook_file(right));
+            }
+        }
+        self.0 &= !(rights.0 & 0xF);
     }
     pub fn insert(&mut self, rights: CastlingRights) {
-        self.0 |= rights.0;
+        self.0 |= rights.0 & 0xF;
+    }
+    /// Insert a single right castling with the rook on `file`.
+    pub fn insert_with_file(&mut self, right: CastlingRights, file: u8) {
+        self.insert(right);
+        self.set_file(right.index(), file);
+    }
+    fn set_file(&mut self, index: u8, file: u8) {
+        let shift = Self::FILE_SHIFT + 3 * index;
+        self.0 = (self.0 & !(0b111 << shift)) | (((file & 0b111) as u16) << shift);
+    }
+    fn index(&self) -> u8 {
+        debug_assert_eq!((self.0 & 0xF).count_ones(), 1, "expected a single castling right");
+        (self.0 & 0xF).trailing_zeros() as u8
     }
 
     // Conversions
@@ -62,13 +107,19 @@ impl CastlingRights {
         s
     }
     pub fn to_u8(&self) -> u8 {
-        self.0
+        (self.0 & 0xF) as u8
     }
 }
 impl BitOr for CastlingRights {
     type Output = Self;
 
     fn bitor(self, rhs: Self) -> Self::Output {
-        CastlingRights(self.0 | rhs.0)
+        let mut rights = self;
+        for right in CastlingRights::EACH {
+            if rhs.contains(right) {
+                rights.insert_with_file(right, rhs.rook_file(right));
+            }
+        }
+        rights
     }
 }
\ No newline at end of file
@@ -8,4 +8,10 @@ impl CastlingRights
     pub const BLACK_KINGSIDE: CastlingRights = CastlingRights::new(0b0100);
     pub const BLACK_QUEENSIDE: CastlingRights = CastlingRights::new(0b1000);
     pub const ALL: CastlingRights = CastlingRights::new(0b1111);
+    pub const EACH: [CastlingRights; 4] = [
+        Self::WHITE_KINGSIDE,
+        Self::WHITE_QUEENSIDE,
+        Self::BLACK_KINGSIDE,
+        Self::BLACK_QUEENSIDE,
+    ];
 }
\ No newline at end of file
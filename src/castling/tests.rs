@@ -1,4 +1,4 @@
-use crate::CastlingRights;
+use crate::{CastlingRights, Tile};
 
 
 #[test]
@@ -47,3 +47,19 @@ fn display() {
         "Kk"
     );
 }
+
+#[test]
+fn rook_files() {
+    let mut rights = CastlingRights::NONE;
+    assert_eq!(rights.rook_file(CastlingRights::WHITE_KINGSIDE), 7);
+    assert_eq!(rights.rook_file(CastlingRights::BLACK_QUEENSIDE), 0);
+
+    rights.insert_with_file(CastlingRights::WHITE_KINGSIDE, 6);
+    rights.insert_with_file(CastlingRights::BLACK_QUEENSIDE, 1);
+    assert_eq!(rights.rook_tile(CastlingRights::WHITE_KINGSIDE), Tile::G1);
+    assert_eq!(rights.rook_tile(CastlingRights::BLACK_QUEENSIDE), Tile::B8);
+
+    // Losing a right forgets its file, so rights compare equal however they were reached
+    rights.remove(CastlingRights::WHITE_KINGSIDE | CastlingRights::BLACK_QUEENSIDE);
+    assert_eq!(rights, CastlingRights::NONE);
+}
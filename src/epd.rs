@@ -0,0 +1,288 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt::{self, Display};
+
+use crate::{Board, FenError, Move};
+
+/// A single EPD operation, move operands are resolved against the position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EpdOp {
+    /// `bm`
+    BestMoves(Vec<Move>),
+    /// `am`
+    AvoidMoves(Vec<Move>),
+    /// `id`
+    Id(String),
+    /// `c0` to `c9`
+    Comment(u8, String),
+    /// `acd`
+    AnalysisDepth(u32),
+    /// `ce`, in centipawns from the side to move
+    Eval(i32),
+    /// `pv`, each move is played before resolving the next one
+    Pv(Vec<Move>),
+    /// `hmvc`
+    HalfMoveClock(u32),
+    /// `fmvn`
+    FullMoveNumber(u32),
+    /// Any other opcode, like the `D1 20` depth entries of perft suites
+    Other { opcode: String, operands: Vec<String> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EpdError {
+    Fen(FenError),
+    MissingOperand { opcode: String },
+    InvalidOperand { opcode: String, operand: String },
+    UnterminatedString,
+}
+
+impl Display for EpdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EpdError::Fen(e) => write!(f, "{}", e),
+            EpdError::MissingOperand { opcode } => write!(f, "missing operand for '{}'", opcode),
+            EpdError::InvalidOperand { opcode, operand } => {
+                write!(f, "invalid operand '{}' for '{}'", operand, opcode)
+            }
+            EpdError::UnterminatedString => write!(f, "unterminated string operand"),
+        }
+    }
+}
+
+impl std::error::Error for EpdError {}
+
+impl From<FenError> for EpdError {
+    fn from(e: FenError) -> Self {
+        EpdError::Fen(e)
+    }
+}
+
+/// A position from the first four FEN fields plus its operations.
+#[derive(Debug, Clone)]
+pub struct Epd {
+    pub board: Board,
+    pub ops: Vec<EpdOp>,
+}
+
+impl Epd {
+    pub fn new(board: Board) -> Self {
+        Self { board, ops: Vec::new() }
+    }
+
+    /// Parse an EPD line. `hmvc` and `fmvn` also set the board's move counters.
+    pub fn parse(line: &str) -> Result<Self, EpdError> {
+        let mut rest = line.trim();
+        let mut fields = Vec::with_capacity(4);
+        for field in 0..4 {
+            if rest.is_empty() {
+                return Err(FenError::MissingField { field }.into());
+            }
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            fields.push(&rest[..end]);
+            rest = rest[end..].trim_start();
+        }
+        let board = Board::new_from_fen(&format!("{} 0 1", fields.join(" ")))?;
+
+        let mut epd = Self::new(board);
+        for tokens in split_operations(rest)? {
+            let op = epd.parse_op(tokens)?;
+            match op {
+                EpdOp::HalfMoveClock(n) => epd.board.half_moves = n,
+                EpdOp::FullMoveNumber(n) => epd.board.full_move = n,
+                _ => {}
+            }
+            epd.ops.push(op);
+        }
+        Ok(epd)
+    }
+
+    fn parse_op(&self, tokens: Vec<String>) -> Result<EpdOp, EpdError> {
+        let mut tokens = tokens.into_iter();
+        let opcode = tokens.next().unwrap_or_default();
+        let operands: Vec<String> = tokens.collect();
+        let invalid = |operand: &str| EpdError::InvalidOperand {
+            opcode: opcode.clone(),
+            operand: operand.to_string(),
+        };
+        let single = || match operands.as_slice() {
+            [operand] => Ok(operand.as_str()),
+            [] => Err(EpdError::MissingOperand { opcode: opcode.clone() }),
+            [_, extra, ..] => Err(invalid(extra)),
+        };
+
+        let op = match opcode.as_str() {
+            "bm" | "am" => {
+                if operands.is_empty() {
+                    return Err(EpdError::MissingOperand { opcode });
+                }
+                let moves = operands
+                    .iter()
+                    .map(|san| self.board.move_from_algebraic(san).ok_or_else(|| invalid(san)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if opcode == "bm" { EpdOp::BestMoves(moves) } else { EpdOp::AvoidMoves(moves) }
+            }
+            "pv" => {
+                let mut board = self.board.clone();
+                let mut moves = Vec::with_capacity(operands.len());
+                for san in &operands {
+                    let mov = board.move_from_algebraic(san).ok_or_else(|| invalid(san))?;
+                    board.make_move(mov);
+                    moves.push(mov);
+                }
+                EpdOp::Pv(moves)
+            }
+            "id" => EpdOp::Id(single()?.to_string()),
+            "acd" => EpdOp::AnalysisDepth(parse_number(&opcode, single()?)?),
+            "ce" => EpdOp::Eval(parse_number(&opcode, single()?)?),
+            "hmvc" => EpdOp::HalfMoveClock(parse_number(&opcode, single()?)?),
+            "fmvn" => {
+                let n = parse_number(&opcode, single()?)?;
+                if n == 0 {
+                    return Err(invalid("0"));
+                }
+                EpdOp::FullMoveNumber(n)
+            }
+            _ => match comment_index(&opcode) {
+                Some(i) => EpdOp::Comment(i, single()?.to_string()),
+                None => EpdOp::Other { opcode, operands },
+            },
+        };
+        Ok(op)
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.ops.iter().find_map(|op| match op {
+            EpdOp::Id(id) => Some(id.as_str()),
+            _ => None,
+        })
+    }
+    pub fn best_moves(&self) -> &[Move] {
+        self.ops
+            .iter()
+            .find_map(|op| match op {
+                EpdOp::BestMoves(moves) => Some(moves.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Write the position and operations back out, with moves in SAN.
+    pub fn to_epd(&self) -> String {
+        let fen = self.board.to_fen();
+        let mut epd = fen.split(' ').take(4).collect::<Vec<_>>().join(" ");
+
+        for op in &self.ops {
+            epd.push(' ');
+            epd.push_str(&self.format_op(op));
+            epd.push(';');
+        }
+        epd
+    }
+
+    fn format_op(&self, op: &EpdOp) -> String {
+        // Alternatives from the current position, each taken back after its suffix is known
+        let sans = |moves: &[Move]| {
+            let mut board = self.board.clone();
+            let sans: Vec<String> = moves
+                .iter()
+                .map(|&mov| {
+                    let san = board.make_move_san(mov);
+                    board.unmake_move();
+                    san
+                })
+                .collect();
+            sans.join(" ")
+        };
+        match op {
+            EpdOp::BestMoves(moves) => format!("bm {}", sans(moves)),
+            EpdOp::AvoidMoves(moves) => format!("am {}", sans(moves)),
+            EpdOp::Pv(moves) => {
+                let mut board = self.board.clone();
+                let mut s = String::from("pv");
+                for &mov in moves {
+                    s.push(' ');
+                    s.push_str(&board.make_move_san(mov));
+                }
+                s
+            }
+            EpdOp::Id(id) => format!("id \"{}\"", id),
+            EpdOp::Comment(i, text) => format!("c{} \"{}\"", i, text),
+            EpdOp::AnalysisDepth(n) => format!("acd {}", n),
+            EpdOp::Eval(n) => format!("ce {}", n),
+            EpdOp::HalfMoveClock(n) => format!("hmvc {}", n),
+            EpdOp::FullMoveNumber(n) => format!("fmvn {}", n),
+            EpdOp::Other { opcode, operands } => {
+                let mut s = opcode.clone();
+                for operand in operands {
+                    s.push(' ');
+                    if operand.contains([' ', ';']) || operand.is_empty() {
+                        s.push_str(&format!("\"{}\"", operand));
+                    } else {
+                        s.push_str(operand);
+                    }
+                }
+                s
+            }
+        }
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(opcode: &str, operand: &str) -> Result<T, EpdError> {
+    operand.parse().map_err(|_| EpdError::InvalidOperand {
+        opcode: opcode.to_string(),
+        operand: operand.to_string(),
+    })
+}
+
+fn comment_index(opcode: &str) -> Option<u8> {
+    match opcode.as_bytes() {
+        [b'c', d @ b'0'..=b'9'] => Some(d - b'0'),
+        _ => None,
+    }
+}
+
+// Split `op a b; op "quoted; string";` into token lists, the last `;` is optional
+fn split_operations(text: &str) -> Result<Vec<Vec<String>>, EpdError> {
+    let mut ops = Vec::new();
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            ';' => {
+                if !tokens.is_empty() {
+                    ops.push(std::mem::take(&mut tokens));
+                }
+            }
+            '"' => {
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => s.push(c),
+                        None => return Err(EpdError::UnterminatedString),
+                    }
+                }
+                tokens.push(s);
+            }
+            c if c.is_whitespace() => {}
+            c => {
+                let mut s = String::from(c);
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == ';' || c == '"' {
+                        break;
+                    }
+                    s.push(c);
+                    chars.next();
+                }
+                tokens.push(s);
+            }
+        }
+    }
+    if !tokens.is_empty() {
+        ops.push(tokens);
+    }
+    Ok(ops)
+}
@@ -0,0 +1,86 @@
+use crate::{Board, Epd, EpdError, EpdOp, FenError, Tile};
+
+#[test]
+fn parse_bratko_kopec() {
+    let epd = Epd::parse("1k1r4/pp1b1R2/3q2pp/4p3/2B5/4Q3/PPP2B2/2K5 b - - bm Qd1+; id \"BK.01\";").unwrap();
+    assert_eq!(epd.id(), Some("BK.01"));
+    assert_eq!(epd.best_moves().len(), 1);
+    assert_eq!((epd.best_moves()[0].from(), epd.best_moves()[0].to()), (Tile::D6, Tile::D1));
+    assert!(!epd.board.white_turn);
+}
+
+#[test]
+fn san_operands() {
+    let epd = Epd::parse("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - bm e4 d4; am Nh3; pv e4 e5 Nf3 Nc6;").unwrap();
+    assert_eq!(epd.best_moves().iter().map(|m| m.to_uci()).collect::<Vec<_>>(), ["e2e4", "d2d4"]);
+    assert!(matches!(&epd.ops[1], EpdOp::AvoidMoves(m) if m[0].to_uci() == "g1h3"));
+    match &epd.ops[2] {
+        EpdOp::Pv(pv) => assert_eq!(pv.iter().map(|m| m.to_uci()).collect::<Vec<_>>(), ["e2e4", "e7e5", "g1f3", "b8c6"]),
+        op => panic!("expected a pv, got {:?}", op),
+    }
+}
+
+#[test]
+fn numeric_and_comment_ops() {
+    let epd = Epd::parse("4k3/8/8/8/8/8/8/4K3 w - - acd 12; ce -35; hmvc 7; fmvn 40; c0 \"a; b\"; c9 x;").unwrap();
+    assert_eq!(
+        epd.ops,
+        [
+            EpdOp::AnalysisDepth(12),
+            EpdOp::Eval(-35),
+            EpdOp::HalfMoveClock(7),
+            EpdOp::FullMoveNumber(40),
+            EpdOp::Comment(0, "a; b".to_string()),
+            EpdOp::Comment(9, "x".to_string()),
+        ]
+    );
+    assert_eq!(epd.board.half_moves, 7);
+    assert_eq!(epd.board.full_move, 40);
+}
+
+#[test]
+fn unknown_opcodes_are_kept() {
+    let epd = Epd::parse("4k3/8/8/8/8/8/8/4K2R w K - ;D1 15 ;D2 66").unwrap();
+    assert_eq!(epd.ops.len(), 2);
+    assert_eq!(epd.ops[1], EpdOp::Other { opcode: "D2".to_string(), operands: vec!["66".to_string()] });
+    assert_eq!(epd.to_epd(), "4k3/8/8/8/8/8/8/4K2R w K - D1 15; D2 66;");
+}
+
+#[test]
+fn round_trip() {
+    let lines = [
+        "1k1r4/pp1b1R2/3q2pp/4p3/2B5/4Q3/PPP2B2/2K5 b - - bm Qd1+; id \"BK.01\";",
+        "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - bm O-O; am O-O-O Rxa8+; c1 \"castle\";",
+        "rnbqkbnr/pppp1ppp/8/4p3/6P1/5P2/PPPPP2P/RNBQKBNR b KQkq - bm Qh4#; pv Qh4#;",
+        "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq d6 pv exd5 Qxd5 Nc3; acd 3; ce 20;",
+    ];
+    for line in lines {
+        let epd = Epd::parse(line).unwrap();
+        assert_eq!(epd.to_epd(), line);
+        assert_eq!(Epd::parse(&epd.to_epd()).unwrap().ops, epd.ops);
+    }
+}
+
+#[test]
+fn to_epd_from_board() {
+    let mut epd = Epd::new(Board::new());
+    epd.ops.push(EpdOp::Id("start".to_string()));
+    assert_eq!(epd.to_epd(), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - id \"start\";");
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(Epd::parse("8/8/8/8/8/8/8/8 w").unwrap_err(), EpdError::Fen(FenError::MissingField { field: 2 }));
+    assert_eq!(
+        Epd::parse("4k3/8/8/8/8/8/8/4K3 w - - bm Qd1;").unwrap_err(),
+        EpdError::InvalidOperand { opcode: "bm".to_string(), operand: "Qd1".to_string() }
+    );
+    assert_eq!(
+        Epd::parse("4k3/8/8/8/8/8/8/4K3 w - - acd;").unwrap_err(),
+        EpdError::MissingOperand { opcode: "acd".to_string() }
+    );
+    assert_eq!(
+        Epd::parse("4k3/8/8/8/8/8/8/4K3 w - - id \"open;").unwrap_err(),
+        EpdError::UnterminatedString
+    );
+}
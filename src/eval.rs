@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{Board, Piece, Tile};
+
+/// Plain piece values in centipawns, used where a single number per piece is enough.
+pub const PIECE_VALUES: [i32; 6] = [100, 320, 330, 500, 900, 0];
+
+// Material for the middlegame and the endgame
+const MG_VALUES: [i32; 6] = [82, 337, 365, 477, 1025, 0];
+const EG_VALUES: [i32; 6] = [94, 281, 297, 512, 936, 0];
+
+// Contribution of each piece to the game phase, the start position is `MAX_PHASE`
+const PHASE_WEIGHTS: [i32; 6] = [0, 1, 1, 2, 4, 0];
+pub const MAX_PHASE: i32 = 24;
+
+/// Scores a position, implement it to plug your own evaluation into `Board::search_with`.
+pub trait Evaluator {
+    /// Centipawns from the side to move's point of view.
+    fn evaluate(&self, board: &Board) -> i32;
+    /// Called on the search's own copy of the board, to set up any incremental state.
+    fn prepare(&self, _board: &mut Board) {}
+}
+
+/// Material and piece-square tables, interpolated between middlegame and endgame by the material left.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TaperedEvaluator;
+
+impl Evaluator for TaperedEvaluator {
+    fn evaluate(&self, board: &Board) -> i32 {
+        match board.accumulator() {
+            Some(acc) => acc.score(board.white_turn),
+            None => EvalAccumulator::from_board(board).score(board.white_turn),
+        }
+    }
+
+    fn prepare(&self, board: &mut Board) {
+        board.enable_accumulator();
+    }
+}
+
+/// Running material, piece-square and phase sums, kept up to date by the board once enabled
+/// with `Board::enable_accumulator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EvalAccumulator {
+    // Indexed white then black
+    material: [i32; 2],
+    mg: [i32; 2],
+    eg: [i32; 2],
+    phase: i32,
+}
+
+impl EvalAccumulator {
+    /// Sum everything from scratch.
+    pub fn from_board(board: &Board) -> Self {
+        let mut acc = Self::default();
+        for white in [true, false] {
+            let (player, _) = board.get_players(white);
+            for piece in Piece::ALL_PIECES {
+                for tile in player.bb[piece as usize].iter() {
+                    acc.add(piece, white, tile);
+                }
+            }
+        }
+        acc
+    }
+
+    pub fn add(&mut self, piece: Piece, white: bool, tile: Tile) {
+        let side = side_index(white);
+        let (mg, eg) = piece_square(piece, white, tile);
+        self.material[side] += PIECE_VALUES[piece as usize];
+        self.mg[side] += mg;
+        self.eg[side] += eg;
+        self.phase += PHASE_WEIGHTS[piece as usize];
+    }
+    pub fn remove(&mut self, piece: Piece, white: bool, tile: Tile) {
+        let side = side_index(white);
+        let (mg, eg) = piece_square(piece, white, tile);
+        self.material[side] -= PIECE_VALUES[piece as usize];
+        self.mg[side] -= mg;
+        self.eg[side] -= eg;
+        self.phase -= PHASE_WEIGHTS[piece as usize];
+    }
+
+    pub fn material(&self, white: bool) -> i32 {
+        self.material[side_index(white)]
+    }
+    pub fn mg(&self, white: bool) -> i32 {
+        self.mg[side_index(white)]
+    }
+    pub fn eg(&self, white: bool) -> i32 {
+        self.eg[side_index(white)]
+    }
+    pub fn phase(&self) -> i32 {
+        self.phase
+    }
+
+    /// Tapered score from `white`'s point of view.
+    pub fn score(&self, white: bool) -> i32 {
+        let score = taper(self.mg(true) - self.mg(false), self.eg(true) - self.eg(false), self.phase);
+        if white { score } else { -score }
+    }
+}
+
+fn side_index(white: bool) -> usize {
+    if white { 0 } else { 1 }
+}
+
+impl Board {
+    /// Start keeping an `EvalAccumulator` up to date through every piece placed or removed.
+    pub fn enable_accumulator(&mut self) {
+        self.accumulator = Some(EvalAccumulator::from_board(self));
+    }
+    pub fn disable_accumulator(&mut self) {
+        self.accumulator = None;
+    }
+    pub fn accumulator(&self) -> Option<&EvalAccumulator> {
+        self.accumulator.as_ref()
+    }
+}
+
+/// Middlegame and endgame value of a piece on a tile, material included, from its own side's point of view.
+pub fn piece_square(piece: Piece, white: bool, tile: Tile) -> (i32, i32) {
+    // The tables are laid out with rank 8 first, as seen by white
+    let index = if white { tile.to_usize() ^ 56 } else { tile.to_usize() };
+    let p = piece as usize;
+    (MG_VALUES[p] + MG_TABLES[p][index], EG_VALUES[p] + EG_TABLES[p][index])
+}
+
+/// How much a piece counts towards the game phase.
+pub fn phase_weight(piece: Piece) -> i32 {
+    PHASE_WEIGHTS[piece as usize]
+}
+
+/// Blend middlegame and endgame scores, `phase` is clamped to `MAX_PHASE` for early promotions.
+pub fn taper(mg: i32, eg: i32, phase: i32) -> i32 {
+    let phase = phase.min(MAX_PHASE);
+    (mg * phase + eg * (MAX_PHASE - phase)) / MAX_PHASE
+}
+
+#[rustfmt::skip]
+const PAWN_MG: [i32; 64] = [
+     0,   0,   0,   0,   0,   0,   0,   0,
+    50,  50,  50,  50,  50,  50,  50,  50,
+    10,  10,  20,  30,  30,  20,  10,  10,
+     5,   5,  10,  25,  25,  10,   5,   5,
+     0,   0,   0,  20,  20,   0,   0,   0,
+     5,  -5, -10,   0,   0, -10,  -5,   5,
+     5,  10,  10, -20, -20,  10,  10,   5,
+     0,   0,   0,   0,   0,   0,   0,   0,
+];
+#[rustfmt::skip]
+const PAWN_EG: [i32; 64] = [
+     0,   0,   0,   0,   0,   0,   0,   0,
+    90,  90,  90,  90,  90,  90,  90,  90,
+    55,  55,  50,  45,  45,  50,  55,  55,
+    30,  30,  25,  20,  20,  25,  30,  30,
+    15,  15,  10,  10,  10,  10,  15,  15,
+     5,   5,   5,   5,   5,   5,   5,   5,
+     0,   0,   0,   0,   0,   0,   0,   0,
+     0,   0,   0,   0,   0,   0,   0,   0,
+];
+#[rustfmt::skip]
+const KNIGHT: [i32; 64] = [
+   -50, -40, -30, -30, -30, -30, -40, -50,
+   -40, -20,   0,   0,   0,   0, -20, -40,
+   -30,   0,  10,  15,  15,  10,   0, -30,
+   -30,   5,  15,  20,  20,  15,   5, -30,
+   -30,   0,  15,  20,  20,  15,   0, -30,
+   -30,   5,  10,  15,  15,  10,   5, -30,
+   -40, -20,   0,   5,   5,   0, -20, -40,
+   -50, -40, -30, -30, -30, -30, -40, -50,
+];
+#[rustfmt::skip]
+const BISHOP: [i32; 64] = [
+   -20, -10, -10, -10, -10, -10, -10, -20,
+   -10,   0,   0,   0,   0,   0,   0, -10,
+   -10,   0,   5,  10,  10,   5,   0, -10,
+   -10,   5,   5,  10,  10,   5,   5, -10,
+   -10,   0,  10,  10,  10,  10,   0, -10,
+   -10,  10,  10,  10,  10,  10,  10, -10,
+   -10,   5,   0,   0,   0,   0,   5, -10,
+   -20, -10, -10, -10, -10, -10, -10, -20,
+];
+#[rustfmt::skip]
+const ROOK: [i32; 64] = [
+     0,   0,   0,   0,   0,   0,   0,   0,
+     5,  10,  10,  10,  10,  10,  10,   5,
+    -5,   0,   0,   0,   0,   0,   0,  -5,
+    -5,   0,   0,   0,   0,   0,   0,  -5,
+    -5,   0,   0,   0,   0,   0,   0,  -5,
+    -5,   0,   0,   0,   0,   0,   0,  -5,
+    -5,   0,   0,   0,   0,   0,   0,  -5,
+     0,   0,   0,   5,   5,   0,   0,   0,
+];
+#[rustfmt::skip]
+const QUEEN: [i32; 64] = [
+   -20, -10, -10,  -5,  -5, -10, -10, -20,
+   -10,   0,   0,   0,   0,   0,   0, -10,
+   -10,   0,   5,   5,   5,   5,   0, -10,
+    -5,   0,   5,   5,   5,   5,   0,  -5,
+     0,   0,   5,   5,   5,   5,   0,  -5,
+   -10,   5,   5,   5,   5,   5,   0, -10,
+   -10,   0,   5,   0,   0,   0,   0, -10,
+   -20, -10, -10,  -5,  -5, -10, -10, -20,
+];
+#[rustfmt::skip]
+const KING_MG: [i32; 64] = [
+   -30, -40, -40, -50, -50, -40, -40, -30,
+   -30, -40, -40, -50, -50, -40, -40, -30,
+   -30, -40, -40, -50, -50, -40, -40, -30,
+   -30, -40, -40, -50, -50, -40, -40, -30,
+   -20, -30, -30, -40, -40, -30, -30, -20,
+   -10, -20, -20, -20, -20, -20, -20, -10,
+    20,  20,   0,   0,   0,   0,  20,  20,
+    20,  30,  10,   0,   0,  10,  30,  20,
+];
+#[rustfmt::skip]
+const KING_EG: [i32; 64] = [
+   -50, -40, -30, -20, -20, -30, -40, -50,
+   -30, -20, -10,   0,   0, -10, -20, -30,
+   -30, -10,  20,  30,  30,  20, -10, -30,
+   -30, -10,  30,  40,  40,  30, -10, -30,
+   -30, -10,  30,  40,  40,  30, -10, -30,
+   -30, -10,  20,  30,  30,  20, -10, -30,
+   -30, -30,   0,   0,   0,   0, -30, -30,
+   -50, -30, -30, -30, -30, -30, -30, -50,
+];
+
+const MG_TABLES: [[i32; 64]; 6] = [PAWN_MG, KNIGHT, BISHOP, ROOK, QUEEN, KING_MG];
+const EG_TABLES: [[i32; 64]; 6] = [PAWN_EG, KNIGHT, BISHOP, ROOK, QUEEN, KING_EG];
@@ -0,0 +1,100 @@
+use crate::eval::{MAX_PHASE, piece_square, taper};
+use crate::{Board, EvalAccumulator, Evaluator, Piece, SearchLimits, TaperedEvaluator, Tile};
+
+fn eval(fen: &str) -> i32 {
+    TaperedEvaluator.evaluate(&Board::new_from_fen(fen).unwrap())
+}
+
+#[test]
+fn symmetric() {
+    assert_eq!(eval("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"), 0);
+    assert_eq!(eval("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1"), 0);
+
+    // The same position with colours swapped and the board mirrored
+    let white = eval("r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4");
+    let black = eval("rnbqk2r/pppp1ppp/5n2/2b1p3/4P3/2N2N2/PPPP1PPP/R1BQKB1R b KQkq - 4 4");
+    assert_eq!(white, black);
+}
+
+#[test]
+fn side_relative() {
+    let white = eval("4k3/8/8/8/8/8/8/3QK3 w - - 0 1");
+    let black = eval("4k3/8/8/8/8/8/8/3QK3 b - - 0 1");
+    assert!(white > 800);
+    assert_eq!(white, -black);
+}
+
+#[test]
+fn tapered() {
+    // A central king is good in the endgame and bad with queens on the board
+    let (mg_corner, eg_corner) = piece_square(Piece::King, true, Tile::from_str("g1").unwrap());
+    let (mg_center, eg_center) = piece_square(Piece::King, true, Tile::from_str("e4").unwrap());
+    assert!(mg_corner > mg_center && eg_corner < eg_center);
+    assert!(eval("8/8/8/3k4/8/8/8/6K1 b - - 0 1") > 0);
+
+    // Tables are mirrored for black
+    assert_eq!(
+        piece_square(Piece::Knight, true, Tile::from_str("f3").unwrap()),
+        piece_square(Piece::Knight, false, Tile::from_str("f6").unwrap())
+    );
+
+    assert_eq!(taper(100, 0, MAX_PHASE), 100);
+    assert_eq!(taper(100, 0, 0), 0);
+    assert_eq!(taper(100, 0, MAX_PHASE * 2), 100);
+}
+
+#[test]
+fn custom_evaluator() {
+    // Only cares about putting its knight on the rim
+    struct RimKnight;
+    impl Evaluator for RimKnight {
+        fn evaluate(&self, board: &Board) -> i32 {
+            let (player, _) = board.get_players(true);
+            let on_rim = player.bb[Piece::Knight as usize].iter().any(|t| t == Tile::from_str("a3").unwrap());
+            let score = if on_rim { 100 } else { 0 };
+            if board.white_turn { score } else { -score }
+        }
+    }
+    let board = Board::new_from_fen("4k3/8/8/8/8/8/8/1N2K3 w - - 0 1").unwrap();
+    let result = board.search_with(SearchLimits::depth(2), &RimKnight);
+    assert_eq!(result.best_move.unwrap().to_uci(), "b1a3");
+    assert_ne!(board.search(SearchLimits::depth(2)).best_move.unwrap().to_uci(), "b1a3");
+}
+
+#[test]
+fn accumulator_matches_recomputation() {
+    // Castling both ways, en passant, promotions with and without captures, and Chess960 castling
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+    ] {
+        let mut board = Board::new_from_fen(fen).unwrap();
+        assert_eq!(board.accumulator(), None);
+        board.enable_accumulator();
+        let before = *board.accumulator().unwrap();
+        board.for_each_position(3, &mut |board| {
+            assert_eq!(board.accumulator(), Some(&EvalAccumulator::from_board(board)), "{}", board.to_fen());
+        });
+        assert_eq!(board.accumulator(), Some(&before));
+    }
+
+    let mut board = Board::new();
+    board.enable_accumulator();
+    // Evaluation state doesn't count towards equality
+    assert_eq!(board, Board::new());
+    let acc = board.accumulator().unwrap();
+    assert_eq!(acc.material(true), 8 * 100 + 2 * 320 + 2 * 330 + 2 * 500 + 900);
+    assert_eq!(acc.material(true), acc.material(false));
+    assert_eq!(acc.phase(), MAX_PHASE);
+    assert_eq!(acc.score(true), 0);
+
+    // Pieces taken off the board outside of a move
+    board.remove_piece(Tile::from_str("d1").unwrap());
+    assert_eq!(board.accumulator(), Some(&EvalAccumulator::from_board(&board)));
+    assert_eq!(board.accumulator().unwrap().phase(), MAX_PHASE - 4);
+
+    board.disable_accumulator();
+    assert_eq!(board.accumulator(), None);
+}
@@ -0,0 +1,278 @@
+#[cfg(test)]
+mod tests;
+
+use crate::{Board, Move, MoveList, PgnVisitor, PgnWriter};
+
+/// Index of a node in a `GameTree`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone, Default)]
+pub struct GameNode {
+    /// The move leading here, `None` for the root.
+    pub mov: Option<Move>,
+    pub san: String,
+    /// Comments before the move, only written when it starts a variation.
+    pub starting_comments: Vec<String>,
+    pub comments: Vec<String>,
+    pub nags: Vec<u8>,
+    parent: Option<NodeId>,
+    // The first child continues the main line, the rest are variations
+    children: Vec<NodeId>,
+}
+
+impl GameNode {
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+}
+
+/// A game with variations, stored as an arena of nodes plus a cursor.
+/// Deleted branches stay in the arena but are unreachable, their ids must not be reused.
+#[derive(Debug, Clone)]
+pub struct GameTree {
+    start: Board,
+    nodes: Vec<GameNode>,
+    cursor: NodeId,
+    // Position at the cursor
+    board: Board,
+    // Where each open PGN variation branched from, while reading
+    variations: Vec<NodeId>,
+    // Comments read after `(` but before the variation's first move
+    pending_comments: Vec<String>,
+}
+
+impl Default for GameTree {
+    fn default() -> Self {
+        Self::new(Board::new())
+    }
+}
+
+impl GameTree {
+    pub fn new(board: Board) -> Self {
+        Self {
+            start: board.clone(),
+            nodes: vec![GameNode::default()],
+            cursor: NodeId(0),
+            board,
+            variations: Vec::new(),
+            pending_comments: Vec::new(),
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+    pub fn cursor(&self) -> NodeId {
+        self.cursor
+    }
+    pub fn node(&self, id: NodeId) -> &GameNode {
+        &self.nodes[id.0]
+    }
+    pub fn node_mut(&mut self, id: NodeId) -> &mut GameNode {
+        &mut self.nodes[id.0]
+    }
+    pub fn start(&self) -> &Board {
+        &self.start
+    }
+    /// The position at the cursor.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Play `mov` from the cursor and move onto it. A move already in the tree is reused,
+    /// otherwise it becomes the main line if the cursor had none, or a new variation.
+    /// Returns `None` and leaves the tree alone if `mov` isn't legal at the cursor.
+    pub fn add_move(&mut self, mov: Move) -> Option<NodeId> {
+        let mut legal = MoveList::new();
+        self.board.generate_legal_moves(self.board.white_turn, &mut legal);
+        if !legal.contains(&mov) {
+            return None;
+        }
+
+        let existing = self.node(self.cursor).children.iter().find(|&&c| self.node(c).mov == Some(mov));
+        if let Some(&child) = existing {
+            self.board.make_move(mov);
+            self.cursor = child;
+            return Some(child);
+        }
+
+        let san = self.board.make_move_san(mov);
+
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(GameNode { mov: Some(mov), san, parent: Some(self.cursor), ..Default::default() });
+        self.nodes[self.cursor.0].children.push(id);
+        self.cursor = id;
+        Some(id)
+    }
+
+    /// Follow the main line one move, returns `false` at the end of the line.
+    pub fn forward(&mut self) -> bool {
+        match self.node(self.cursor).children.first() {
+            Some(&child) => {
+                self.board.make_move(self.node(child).mov.unwrap());
+                self.cursor = child;
+                true
+            }
+            None => false,
+        }
+    }
+    /// Take back one move, returns `false` at the root.
+    pub fn back(&mut self) -> bool {
+        match self.node(self.cursor).parent {
+            Some(parent) => {
+                self.board.unmake_move();
+                self.cursor = parent;
+                true
+            }
+            None => false,
+        }
+    }
+    pub fn goto(&mut self, id: NodeId) {
+        self.board = self.board_at(id);
+        self.cursor = id;
+    }
+    pub fn goto_start(&mut self) {
+        while self.back() {}
+    }
+    pub fn goto_end(&mut self) {
+        while self.forward() {}
+    }
+
+    /// Nodes from the root (excluded) down to `id`.
+    pub fn path(&self, id: NodeId) -> Vec<NodeId> {
+        let mut path = Vec::new();
+        let mut node = id;
+        while let Some(parent) = self.node(node).parent {
+            path.push(node);
+            node = parent;
+        }
+        path.reverse();
+        path
+    }
+    pub fn mainline(&self) -> Vec<NodeId> {
+        let mut line = Vec::new();
+        let mut node = self.root();
+        while let Some(&child) = self.node(node).children.first() {
+            line.push(child);
+            node = child;
+        }
+        line
+    }
+    /// Replay the start position up to `id`.
+    pub fn board_at(&self, id: NodeId) -> Board {
+        let mut board = self.start.clone();
+        for node in self.path(id) {
+            board.make_move(self.node(node).mov.unwrap());
+        }
+        board
+    }
+
+    /// Make every variation on the way to `id` the main line of its parent.
+    pub fn promote_to_mainline(&mut self, id: NodeId) {
+        let mut node = id;
+        while let Some(parent) = self.node(node).parent {
+            let children = &mut self.nodes[parent.0].children;
+            let index = children.iter().position(|&c| c == node).unwrap();
+            children[..=index].rotate_right(1);
+            node = parent;
+        }
+    }
+    /// Remove `id` and everything after it, a cursor inside the branch moves to its parent.
+    /// Deleting the root clears the whole tree.
+    pub fn delete_branch(&mut self, id: NodeId) {
+        let Some(parent) = self.node(id).parent else {
+            *self = Self::new(self.start.clone());
+            return;
+        };
+        if self.path(self.cursor).contains(&id) {
+            self.goto(parent);
+        }
+        self.nodes[parent.0].children.retain(|&c| c != id);
+        self.nodes[id.0].parent = None;
+    }
+
+    /// Write the tree as PGN movetext, with comments, NAGs and variations.
+    pub fn to_pgn_writer(&self) -> PgnWriter {
+        let mut writer = PgnWriter::new(self.start.clone());
+        for comment in &self.node(self.root()).comments {
+            writer.push_comment(comment);
+        }
+        self.write_line(self.root(), &mut writer);
+        writer
+    }
+    fn write_node(&self, id: NodeId, writer: &mut PgnWriter) {
+        let node = self.node(id);
+        writer.push_move(node.mov.unwrap());
+        for &nag in &node.nags {
+            writer.push_nag(nag);
+        }
+        for comment in &node.comments {
+            writer.push_comment(comment);
+        }
+    }
+    // The main move after `id`, its alternatives, then the rest of the line
+    fn write_line(&self, id: NodeId, writer: &mut PgnWriter) {
+        let Some((&main, variations)) = self.node(id).children.split_first() else {
+            return;
+        };
+        self.write_node(main, writer);
+        for &variation in variations {
+            writer.begin_variation();
+            for comment in &self.node(variation).starting_comments {
+                writer.push_comment(comment);
+            }
+            self.write_node(variation, writer);
+            self.write_line(variation, writer);
+            writer.end_variation();
+        }
+        self.write_line(main, writer);
+    }
+}
+
+impl PgnVisitor for GameTree {
+    fn begin_game(&mut self) {
+        *self = Self::default();
+    }
+    fn end_tags(&mut self, board: &Board) -> bool {
+        *self = Self::new(board.clone());
+        true
+    }
+    fn san(&mut self, _board: &Board, _san: &str, mov: Move) {
+        if let Some(id) = self.add_move(mov) {
+            let pending = std::mem::take(&mut self.pending_comments);
+            self.node_mut(id).starting_comments.extend(pending);
+        }
+    }
+    fn nag(&mut self, nag: u8) {
+        let cursor = self.cursor;
+        self.node_mut(cursor).nags.push(nag);
+    }
+    fn comment(&mut self, comment: &str) {
+        // Inside a variation that has no move yet, the comment belongs to its first move
+        if self.variations.last().is_some_and(|&id| self.node(id).parent == Some(self.cursor)) {
+            self.pending_comments.push(comment.to_string());
+            return;
+        }
+        let cursor = self.cursor;
+        self.node_mut(cursor).comments.push(comment.to_string());
+    }
+    fn begin_variation(&mut self) {
+        self.variations.push(self.cursor);
+        self.back();
+    }
+    fn end_variation(&mut self) {
+        // A variation without moves keeps its comments on the move it branched from
+        let pending = std::mem::take(&mut self.pending_comments);
+        if let Some(id) = self.variations.pop() {
+            self.goto(id);
+            self.node_mut(id).comments.extend(pending);
+        }
+    }
+    fn end_game(&mut self) {
+        self.goto_start();
+    }
+}
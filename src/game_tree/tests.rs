@@ -0,0 +1,141 @@
+use crate::{Board, GameTree, Move, NodeId, PgnReader, Tile};
+
+fn play(tree: &mut GameTree, san: &str) -> NodeId {
+    let mov = tree.board().move_from_algebraic(san).unwrap();
+    tree.add_move(mov).unwrap()
+}
+
+fn sans(tree: &GameTree, line: &[NodeId]) -> Vec<String> {
+    line.iter().map(|&id| tree.node(id).san.clone()).collect()
+}
+
+#[test]
+fn navigation() {
+    let mut tree = GameTree::default();
+    let e4 = play(&mut tree, "e4");
+    play(&mut tree, "e5");
+    let qh5 = play(&mut tree, "Qh5");
+    assert_eq!(sans(&tree, &tree.mainline()), ["e4", "e5", "Qh5"]);
+
+    assert!(tree.back());
+    assert!(tree.back());
+    assert_eq!(tree.cursor(), e4);
+    assert_eq!(tree.board().to_fen(), "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1");
+    assert!(tree.forward());
+    assert!(tree.forward());
+    assert!(!tree.forward());
+    assert_eq!(tree.cursor(), qh5);
+
+    tree.goto_start();
+    assert_eq!(tree.cursor(), tree.root());
+    assert!(!tree.back());
+    assert_eq!(tree.board().to_fen(), Board::new().to_fen());
+
+    tree.goto(qh5);
+    assert_eq!(tree.board().to_fen(), tree.board_at(qh5).to_fen());
+    tree.goto_start();
+    tree.goto_end();
+    assert_eq!(tree.cursor(), qh5);
+}
+
+#[test]
+fn variations_and_promotion() {
+    let mut tree = GameTree::default();
+    let e4 = play(&mut tree, "e4");
+    let e5 = play(&mut tree, "e5");
+    tree.goto(e4);
+    let c5 = play(&mut tree, "c5");
+    let nf3 = play(&mut tree, "Nf3");
+    tree.goto(e4);
+    // Playing an existing move follows it instead of adding a duplicate
+    assert_eq!(play(&mut tree, "c5"), c5);
+
+    assert_eq!(tree.node(e4).children(), [e5, c5]);
+    assert_eq!(tree.node(nf3).parent(), Some(c5));
+    assert_eq!(tree.path(nf3), [e4, c5, nf3]);
+
+    tree.promote_to_mainline(nf3);
+    assert_eq!(tree.node(e4).children(), [c5, e5]);
+    assert_eq!(sans(&tree, &tree.mainline()), ["e4", "c5", "Nf3"]);
+}
+
+#[test]
+fn illegal_moves_are_rejected() {
+    let mut tree = GameTree::default();
+    let e4 = play(&mut tree, "e4");
+    // Moves from an empty tile or by the wrong side
+    assert_eq!(tree.add_move(Move::new(Tile::E4, Tile::E5, 0)), None);
+    assert_eq!(tree.add_move(Move::new(Tile::D2, Tile::D4, 0)), None);
+    assert_eq!(tree.cursor(), e4);
+    assert!(tree.node(e4).children().is_empty());
+    assert_eq!(tree.board().to_fen(), tree.board_at(e4).to_fen());
+}
+
+#[test]
+fn delete_branch() {
+    let mut tree = GameTree::default();
+    let e4 = play(&mut tree, "e4");
+    let e5 = play(&mut tree, "e5");
+    tree.goto(e4);
+    let c5 = play(&mut tree, "c5");
+    play(&mut tree, "Nf3");
+
+    tree.delete_branch(c5);
+    assert_eq!(tree.cursor(), e4);
+    assert_eq!(tree.node(e4).children(), [e5]);
+    assert_eq!(tree.board().to_fen(), tree.board_at(e4).to_fen());
+
+    tree.delete_branch(tree.root());
+    assert!(tree.mainline().is_empty());
+    assert_eq!(tree.cursor(), tree.root());
+}
+
+#[test]
+fn annotations_to_pgn() {
+    let mut tree = GameTree::new(Board::new_from_fen("3k4/8/8/8/8/8/8/R3K3 w Q - 0 1").unwrap());
+    let castle = play(&mut tree, "O-O-O");
+    tree.node_mut(castle).nags.push(1);
+    tree.node_mut(castle).comments.push("check".to_string());
+    play(&mut tree, "Ke7");
+    tree.goto(castle);
+    play(&mut tree, "Kc7");
+
+    assert_eq!(tree.node(castle).san, "O-O-O+");
+    let pgn = tree.to_pgn_writer().finish();
+    assert!(pgn.ends_with("\n\n1. O-O-O+ $1 {check} 1... Ke7 (1... Kc7) *\n"), "{}", pgn);
+}
+
+#[test]
+fn read_from_pgn() {
+    let pgn = "{opening} 1. e4 e5 (1... c5 2. Nf3 (2. Nc3) 2... d6) 2. Nf3 $1 *";
+    let mut tree = GameTree::default();
+    assert!(PgnReader::new(pgn.as_bytes()).read_game(&mut tree).unwrap());
+    assert_eq!(tree.cursor(), tree.root());
+    assert_eq!(tree.node(tree.root()).comments, ["opening"]);
+    assert_eq!(sans(&tree, &tree.mainline()), ["e4", "e5", "Nf3"]);
+
+    let e4 = tree.mainline()[0];
+    let c5 = tree.node(e4).children()[1];
+    assert_eq!(tree.node(c5).san, "c5");
+    let nf3 = tree.node(c5).children()[0];
+    assert_eq!(sans(&tree, tree.node(nf3).children()), ["d6"]);
+    assert_eq!(sans(&tree, tree.node(c5).children()), ["Nf3", "Nc3"]);
+
+    let out = tree.to_pgn_writer().finish();
+    assert!(out.ends_with(&format!("\n\n{}\n", pgn)), "{}", out);
+}
+
+#[test]
+fn variation_starting_comments() {
+    let pgn = "1. e4 ({alt} 1. d4 d5) 1... e5 *";
+    let mut tree = GameTree::default();
+    assert!(PgnReader::new(pgn.as_bytes()).read_game(&mut tree).unwrap());
+
+    let e4 = tree.mainline()[0];
+    let d4 = tree.node(tree.root()).children()[1];
+    assert!(tree.node(e4).comments.is_empty());
+    assert_eq!(tree.node(d4).starting_comments, ["alt"]);
+
+    let out = tree.to_pgn_writer().finish();
+    assert!(out.ends_with(&format!("\n\n{}\n", pgn)), "{}", out);
+}
@@ -1,16 +1,22 @@
 pub mod board;
 pub use board::Board;
+pub use board::perft::{PerftOptions, PerftStats};
+pub use board::debug::format_divide;
+pub use board::retro::RetroIssue;
 pub mod player;
 pub use player::Player;
 
 pub mod r#move;
 pub use r#move::Move;
+pub use r#move::DecodedMove;
 pub mod move_list;
 pub use move_list::MoveList;
 pub mod move_enums;
 pub use move_enums::MoveError;
 pub use move_enums::GameState;
 pub use move_enums::MoveResult;
+pub use move_enums::GenType;
+pub use move_enums::{FenError, FenMode, PositionError, SanError, UciMoveError};
 pub mod castling;
 pub use castling::CastlingRights;
 
@@ -23,6 +29,7 @@ pub use tile::Tile;
 pub mod magics;
 pub use magics::MagicEntry;
 pub use magics::between::BETWEEN;
+pub use magics::line::LINE;
 pub use magics::bishop_attacks::BISHOP_ATTACKS;
 pub use magics::bishop_magics::BISHOP_MAGICS;
 pub use magics::king_attacks::KING_ATTACKS;
@@ -30,26 +37,49 @@ pub use magics::knight_attacks::KNIGHT_ATTACKS;
 pub use magics::rook_attacks::ROOK_ATTACKS;
 pub use magics::rook_magics::ROOK_MAGICS;
 pub mod zobrist;
+pub mod perft_suite;
+pub use perft_suite::{
+    DivideDiff, PerftCase, PerftMismatch, SuiteReport, diff_divide, parse_perft_suite, run_perft_suite, run_perft_suite_with,
+};
+pub mod perft_bisect;
+pub use perft_bisect::{PerftBug, UciEngine};
+pub mod epd;
+pub use epd::{Epd, EpdError, EpdOp};
+pub mod pgn;
+pub use pgn::{PgnError, PgnReader, PgnVisitor, PgnWriter};
+pub mod game_tree;
+pub use game_tree::{GameNode, GameTree, NodeId};
+pub mod search;
+pub use search::{Score, SearchLimits, SearchResult};
+pub mod eval;
+pub use eval::{EvalAccumulator, Evaluator, TaperedEvaluator};
+pub mod nnue;
+pub use nnue::{Network, NnueError, NnueEvaluator};
 
 #[cfg(test)]
 mod tests {
 
     use crate::board::Board;
+    use crate::{GenType, MoveList, PerftOptions, PerftStats};
 
     // Regular starting position
-    static POSITION_0: &'static str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    static POSITION_0: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
     static NODES_0: &[i64] = &[20, 400, 8_902, 197_281, 4_865_609, 119_060_324, 3_195_901_860];
 
     // Position 3
-    static POSITION_1: &'static str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+    static POSITION_1: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
     static NODES_1: &[i64] = &[14, 191, 2_812, 43_238, 674_624, 11_030_083];
 
     // Position 4
-    static POSITION_2: &'static str = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+    static POSITION_2: &str = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
     static NODES_2: &[i64] = &[6, 264, 9_467, 422_333, 15_833_292, 706_045_033];
 
+    // Position 2 ( Kiwipete )
+    static KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    static NODES_KIWIPETE: &[i64] = &[48, 2_039, 97_862, 4_085_603];
+
     // Position 5
-    static POSITION_3: &'static str = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
+    static POSITION_3: &str = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
     static NODES_3: &[i64] = &[44, 1_486, 62_379, 2_103_487, 89_941_194];
 
     #[test]
@@ -70,7 +100,115 @@ mod tests {
     }
     fn test_position(fen: &str, expected: i64, depth: usize) {
         let mut board = Board::new_from_fen(fen).unwrap();
-        let pos = board.positions_divide(depth);
-        assert_eq!(pos, expected);
+        let pos: u64 = board.positions_divide(depth).iter().map(|(_, n)| n).sum();
+        assert_eq!(pos, expected as u64);
+    }
+
+    #[test]
+    fn chess960() {
+        for (fen, nodes) in [
+            ("bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9", [21, 528, 12_189, 326_672]),
+            ("2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9", [21, 807, 18_002, 667_366]),
+            ("b1q1rrkb/pppppppp/3nn3/8/P7/1PPP4/4PPPP/BQNNRKRB w GE - 1 9", [20, 479, 10_471, 273_318]),
+        ] {
+            let board = Board::new_from_fen(fen).unwrap();
+            assert!(board.chess960);
+            for (depth, &expected) in nodes.iter().enumerate() {
+                assert_eq!(board.perft(depth + 1, PerftOptions::default()), expected, "{fen} depth {}", depth + 1);
+            }
+        }
+        // Standard chess through the Chess960 encoding
+        let board = Board::new_chess960(518).unwrap();
+        assert_eq!(board.perft(4, PerftOptions::default()), NODES_0[3] as u64);
+    }
+
+    #[test]
+    fn perft_stats() {
+        let stats = |fen: &str, depth: usize| Board::new_from_fen(fen).unwrap().perft_stats(depth, PerftOptions::default());
+        assert_eq!(stats(POSITION_0, 4), PerftStats {
+            nodes: 197_281, captures: 1_576, checks: 469, checkmates: 8, ..Default::default()
+        });
+        assert_eq!(stats(KIWIPETE, 3), PerftStats {
+            nodes: 97_862, captures: 17_102, en_passant: 45, castles: 3_162, checks: 993, checkmates: 1,
+            ..Default::default()
+        });
+        assert_eq!(stats(POSITION_1, 5), PerftStats {
+            nodes: 674_624, captures: 52_051, en_passant: 1_165, checks: 52_950, discovered_checks: 1_292,
+            double_checks: 3, ..Default::default()
+        });
+        assert_eq!(stats(POSITION_2, 3), PerftStats {
+            nodes: 9_467, captures: 1_021, en_passant: 4, castles: 0, promotions: 120, checks: 38,
+            discovered_checks: 2, double_checks: 0, checkmates: 22
+        });
+    }
+    #[test]
+    fn perft_threads_and_hash() {
+        let board = Board::new_from_fen(KIWIPETE).unwrap();
+        let options = PerftOptions { threads: 4, hash_entries: 1 << 16 };
+        assert_eq!(board.perft(4, options), NODES_KIWIPETE[3] as u64);
+        assert_eq!(board.perft(4, PerftOptions::default()), NODES_KIWIPETE[3] as u64);
+        assert_eq!(board.perft_stats(3, options).nodes, NODES_KIWIPETE[2] as u64);
+    }
+
+    #[test]
+    fn staged_generation() {
+        for (fen, nodes) in [
+            (POSITION_0, NODES_0),
+            (POSITION_1, NODES_1),
+            (POSITION_2, NODES_2),
+            (POSITION_3, NODES_3),
+            (KIWIPETE, NODES_KIWIPETE),
+        ] {
+            let mut board = Board::new_from_fen(fen).unwrap();
+            assert_eq!(staged_perft(&mut board, 4), nodes[3], "{fen}");
+        }
+    }
+    // Perft built from the captures and quiets stages, checking the other stages on every node
+    fn staged_perft(board: &mut Board, depth: usize) -> i64 {
+        let white = board.white_turn;
+        let mut legal = MoveList::new();
+        board.generate_moves(white, GenType::Legal, &mut legal);
+
+        let mut moves = MoveList::new();
+        board.generate_moves(white, GenType::Captures, &mut moves);
+        let captures = moves.len();
+        board.generate_moves(white, GenType::Quiets, &mut moves);
+        assert_eq!(moves.len(), legal.len());
+        for m in legal.iter() {
+            assert!(moves.iter().any(|s| s == m), "{m:?} missing from the stages");
+        }
+        for (i, m) in moves.iter().enumerate() {
+            let is_capture = m.is_capture() || m.promoted_to().is_some();
+            assert_eq!(is_capture, i < captures, "{m:?} generated in the wrong stage");
+        }
+
+        let mut evasions = MoveList::new();
+        board.generate_moves(white, GenType::Evasions, &mut evasions);
+        let expected = if board.is_in_check(white) { legal.len() } else { 0 };
+        assert_eq!(evasions.len(), expected);
+
+        let mut checks = MoveList::new();
+        board.generate_moves(white, GenType::QuietChecks, &mut checks);
+        let mut quiet_checks = 0;
+        for &m in moves.iter().skip(captures) {
+            board.make_move(m);
+            if board.is_in_check(!white) {
+                quiet_checks += 1;
+                assert!(checks.iter().any(|c| *c == m), "{m:?} gives check");
+            }
+            board.unmake_move();
+        }
+        assert_eq!(checks.len(), quiet_checks);
+
+        if depth == 1 {
+            return moves.len() as i64;
+        }
+        let mut nodes = 0;
+        for &m in moves.iter() {
+            board.make_move(m);
+            nodes += staged_perft(board, depth - 1);
+            board.unmake_move();
+        }
+        nodes
     }
 }
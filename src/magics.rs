@@ -10,6 +10,7 @@ pub mod king_attacks;
 pub mod knight_attacks;
 
 pub mod between;
+pub mod line;
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct MagicEntry {
@@ -1,4 +1,4 @@
-pub const BETWEEN: [[u64; 64]; 64] = [
+pub static BETWEEN: [[u64; 64]; 64] = [
     [
         0x0000000000000000,
         0x0000000000000000,
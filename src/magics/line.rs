@@ -0,0 +1,4226 @@
+pub static LINE: [[u64; 64]; 64] = [
+    [
+        0x0000000000000000,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0101010101010101,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+    ],
+    [
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000102,
+        0x0202020202020202,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x0000000000010204,
+        0x0404040404040404,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000010204,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000001020408,
+        0x0808080808080808,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000001020408,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000001020408,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x1010101010101010,
+        0x0000000080402010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000080402010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000080402010,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x2020202020202020,
+        0x0000000000804020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000804020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x4040404040404040,
+        0x0000000000008040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+    ],
+    [
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x00000000000000ff,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+    ],
+    [
+        0x0101010101010101,
+        0x0000000000000102,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0101010101010101,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+    ],
+    [
+        0x8040201008040201,
+        0x0202020202020202,
+        0x0000000000010204,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000010204,
+        0x0202020202020202,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+    ],
+    [
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0404040404040404,
+        0x0000000001020408,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x0000000001020408,
+        0x0404040404040404,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000001020408,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0808080808080808,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0808080808080808,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x1010101010101010,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x1010101010101010,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000080402010,
+        0x2020202020202020,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x2020202020202020,
+        0x0000000080402010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000080402010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000804020,
+        0x4040404040404040,
+        0x0102040810204080,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x4040404040404040,
+        0x0000000000804020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000008040,
+        0x8080808080808080,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x000000000000ff00,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+    ],
+    [
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000010204,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000010204,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0101010101010101,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000001020408,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0202020202020202,
+        0x0000000001020408,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000001020408,
+        0x0202020202020202,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+    ],
+    [
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0404040404040404,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0404040404040404,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+    ],
+    [
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0808080808080808,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0808080808080808,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x1010101010101010,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x1010101010101010,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x2020202020202020,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x2020202020202020,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000080402010,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000080402010,
+        0x4040404040404040,
+        0x0204081020408000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x4040404040404040,
+        0x0000000080402010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000804020,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000804020,
+        0x8080808080808080,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000ff0000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+    ],
+    [
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000001020408,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000001020408,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000001020408,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0101010101010101,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0202020202020202,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000102040810,
+        0x0202020202020202,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0404040404040404,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0404040404040404,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+    ],
+    [
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0808080808080808,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0808080808080808,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+    ],
+    [
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x1010101010101010,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x1010101010101010,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x2020202020202020,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x2020202020202020,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x4040404040404040,
+        0x0408102040800000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x4040404040404040,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000080402010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000080402010,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000080402010,
+        0x8080808080808080,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x00000000ff000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+    ],
+    [
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000102040810,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0101010101010101,
+        0x0804020100000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0804020100000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0804020100000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0202020202020202,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000010204081020,
+        0x0202020202020202,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0404040404040404,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0404040404040404,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0808080808080808,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0808080808080808,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+    ],
+    [
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x1010101010101010,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x1010101010101010,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+    ],
+    [
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x2020202020202020,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x2020202020202020,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x4040404040404040,
+        0x0810204080000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x4040404040404040,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000008040201008,
+        0x8080808080808080,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x000000ff00000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1020408000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1020408000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1020408000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+    ],
+    [
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000010204081020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0101010101010101,
+        0x0402010000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0402010000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0804020100000000,
+        0x0202020202020202,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0001020408102040,
+        0x0202020202020202,
+        0x0804020100000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0804020100000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0404040404040404,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0404040404040404,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0808080808080808,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0808080808080808,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x1010101010101010,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x1010101010101010,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+    ],
+    [
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x2020202020202020,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x2020202020202020,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x8040201008040201,
+    ],
+    [
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x4040404040404040,
+        0x1020408000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1020408000000000,
+        0x4040404040404040,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1020408000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000804020100804,
+        0x8080808080808080,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000ff0000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2040800000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2040800000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+    ],
+    [
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0001020408102040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0101010101010101,
+        0x0201000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0402010000000000,
+        0x0202020202020202,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0102040810204080,
+        0x0202020202020202,
+        0x0402010000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0804020100000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0804020100000000,
+        0x0404040404040404,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0404040404040404,
+        0x0804020100000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0808080808080808,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0808080808080808,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x1010101010101010,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x1010101010101010,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x1020408000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x2020202020202020,
+        0x1020408000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1020408000000000,
+        0x2020202020202020,
+        0x4020100804020100,
+        0x0000000000000000,
+    ],
+    [
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x4040404040404040,
+        0x2040800000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2040800000000000,
+        0x4040404040404040,
+        0x8040201008040201,
+    ],
+    [
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0080402010080402,
+        0x8080808080808080,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x00ff000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4080000000000000,
+        0x8080808080808080,
+    ],
+    [
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0000000000000000,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0101010101010101,
+        0x0102040810204080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0202020202020202,
+        0x0000000000000000,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0201000000000000,
+        0x0202020202020202,
+        0x0204081020408000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0402010000000000,
+        0x0000000000000000,
+        0x0404040404040404,
+        0x0000000000000000,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0402010000000000,
+        0x0404040404040404,
+        0x0408102040800000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0804020100000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0804020100000000,
+        0x0000000000000000,
+        0x0808080808080808,
+        0x0000000000000000,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0804020100000000,
+        0x0808080808080808,
+        0x0810204080000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1020408000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x0000000000000000,
+        0x1010101010101010,
+        0x0000000000000000,
+        0x1020408000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x1008040201000000,
+        0x1010101010101010,
+        0x1020408000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x0000000000000000,
+        0x2020202020202020,
+        0x0000000000000000,
+        0x2040800000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x2010080402010000,
+        0x2020202020202020,
+        0x2040800000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+    ],
+    [
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x0000000000000000,
+        0x4040404040404040,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x4020100804020100,
+        0x4040404040404040,
+        0x4080000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0x0000000000000000,
+        0xff00000000000000,
+    ],
+    [
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x0000000000000000,
+        0x8080808080808080,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x8040201008040201,
+        0x8080808080808080,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0xff00000000000000,
+        0x0000000000000000,
+    ],
+];
@@ -1,9 +1,11 @@
-use crate::{CastlingRights, Piece, Tile};
 use std::fmt;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+use crate::{Piece, Tile};
+
+/// A move as from, to and a flag, everything needed to take it back lives in the board's undo stack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 #[repr(transparent)]
-pub struct Move(u64);
+pub struct Move(u16);
 
 impl Move {
     // 0 - 5    ->  From    ( Tile )
@@ -12,75 +14,39 @@ impl Move {
     // 6 - 11   ->  To      ( Tile )
     const TO_SHIFT: u8 = 6;
 
-    // 12 - 15  ->  Piece   ( Piece )
-    const PIECE_SHIFT: u8 = 12;
-
-    // 16 - 19  ->  Capture ( Option<Piece> )
-    const CAPTURE_SHIFT: u8 = 16;
-
-    // 20 - 23  ->  Promte  ( Option<Piece> )
-    const PROMO_SHIFT: u8 = 20;
-
-    // 24 - 29  ->  EnPass  ( Option<Piece> )
-    const EP_SHIFT: u8 = 24;
-
-    // 30 - 33  -> Castling ( CastlingRights (u8) )
-    const CASTLE_SHIFT: u8 = 30;
-
-    // 34 - 35  -> WCache   ( Option<bool> )
-    const WHITE_CACHE_SHIFT: u8 = 34;
-    // 36 - 37  -> BCache   ( Option<bool> )
-    const BLACK_CACHE_SHIFT: u8 = 36;
-
-    // 38 - 45 -> PrevHalfMoves (u8)
-    const PREV_HALF_MOVE_SHIFT: u8 = 38;
-
-    fn encode_option_bool(val: Option<bool>) -> u64 {
-        match val {
-            None => 0b00,
-            Some(false) => 0b01,
-            Some(true) => 0b10,
-        }
+    // 12 - 15  ->  Flag    ( see below )
+    const FLAG_SHIFT: u8 = 12;
+
+    // Flags
+    pub const QUIET: u8 = 0b0000;
+    pub const DOUBLE_PUSH: u8 = 0b0001;
+    pub const KING_CASTLE: u8 = 0b0010;
+    pub const QUEEN_CASTLE: u8 = 0b0011;
+    pub const CAPTURE: u8 = 0b0100;
+    pub const EN_PASSANT: u8 = 0b0101;
+    // The low two bits pick the piece, Knight to Queen
+    pub const PROMOTION: u8 = 0b1000;
+    pub const PROMOTION_CAPTURE: u8 = 0b1100;
+
+    pub fn new(from: Tile, to: Tile, flag: u8) -> Self {
+        debug_assert!(flag < 16);
+        let mut data = 0u16;
+        data |= (from.to_u8() as u16) << Self::FROM_SHIFT;
+        data |= (to.to_u8() as u16) << Self::TO_SHIFT;
+        data |= (flag as u16) << Self::FLAG_SHIFT;
+        Self(data)
     }
-
-    fn decode_option_bool(bits: u64) -> Option<bool> {
-        match bits & 0b11 {
-            0b00 => None,
-            0b01 => Some(false),
-            0b10 => Some(true),
-            _ => None,
-        }
+    pub fn new_promotion(from: Tile, to: Tile, promotion: Piece, capture: bool) -> Self {
+        debug_assert!(matches!(promotion, Piece::Knight | Piece::Bishop | Piece::Rook | Piece::Queen));
+        let flag = if capture { Self::PROMOTION_CAPTURE } else { Self::PROMOTION };
+        Self::new(from, to, flag | (promotion as u8 - 1))
     }
-
-    pub fn new(
-        from: Tile,
-        to: Tile,
-
-        piece: Piece,
-        capture: Option<Piece>,
-
-        en_passant: Option<Tile>,
-        prev_castle: CastlingRights,
-        promoted_to: Option<Piece>,
-
-        white_cache: Option<bool>,
-        black_cache: Option<bool>,
-
-        prev_half_moves: u8,
-    ) -> Self {
-        let mut data = 0u64;
-        data |= (from.to_u8() as u64) << Self::FROM_SHIFT;
-        data |= (to.to_u8() as u64) << Self::TO_SHIFT;
-        data |= (piece as u64) << Self::PIECE_SHIFT;
-        data |= (capture.map(|p| p as u64 + 1).unwrap_or(0)) << Self::CAPTURE_SHIFT;
-        data |= (promoted_to.map(|p| p as u64 + 1).unwrap_or(0)) << Self::PROMO_SHIFT;
-        data |= (en_passant.map(|t| t.to_u8()).unwrap_or(0x3F) as u64) << Self::EP_SHIFT;
-        data |= (prev_castle.to_u8() as u64) << Self::CASTLE_SHIFT;
-        data |= Self::encode_option_bool(white_cache) << Self::WHITE_CACHE_SHIFT;
-        data |= Self::encode_option_bool(black_cache) << Self::BLACK_CACHE_SHIFT;
-        data |= (prev_half_moves as u64) << Self::PREV_HALF_MOVE_SHIFT;
+    pub fn from_u16(data: u16) -> Self {
         Self(data)
     }
+    pub fn to_u16(&self) -> u16 {
+        self.0
+    }
 
     pub fn from(&self) -> Tile {
         Tile::new_unchecked(((self.0 >> Self::FROM_SHIFT) & 0x3F) as u8)
@@ -88,37 +54,46 @@ impl Move {
     pub fn to(&self) -> Tile {
         Tile::new_unchecked(((self.0 >> Self::TO_SHIFT) & 0x3F) as u8)
     }
-    pub fn piece(&self) -> Piece {
-        unsafe { std::mem::transmute(((self.0 >> Self::PIECE_SHIFT) & 0xF) as u8) }
-    }
-    pub fn capture(&self) -> Option<Piece> {
-        let v = (self.0 >> Self::CAPTURE_SHIFT) & 0xF;
-        (v != 0).then(|| unsafe { std::mem::transmute((v - 1) as u8) })
+    pub fn flag(&self) -> u8 {
+        ((self.0 >> Self::FLAG_SHIFT) & 0xF) as u8
     }
-    pub fn promoted_to(&self) -> Option<Piece> {
-        let v = (self.0 >> Self::PROMO_SHIFT) & 0xF;
-        (v != 0).then(|| unsafe { std::mem::transmute((v - 1) as u8) })
+    pub fn is_capture(&self) -> bool {
+        self.flag() & Self::CAPTURE != 0
     }
-    pub fn en_passant(&self) -> Option<Tile> {
-        let v = (self.0 >> Self::EP_SHIFT) & 0x3F;
-        (v != 0x3F).then(|| Tile::new_unchecked(v as u8))
+    pub fn is_en_passant(&self) -> bool {
+        self.flag() == Self::EN_PASSANT
     }
-    pub fn prev_castle(&self) -> CastlingRights {
-        CastlingRights::new(((self.0 >> Self::CASTLE_SHIFT) & 0xF) as u8)
+    pub fn is_castle(&self) -> bool {
+        matches!(self.flag(), Self::KING_CASTLE | Self::QUEEN_CASTLE)
     }
-    pub fn white_cache(&self) -> Option<bool> {
-        Self::decode_option_bool((self.0 >> Self::WHITE_CACHE_SHIFT) & 0b11)
+    pub fn is_double_push(&self) -> bool {
+        self.flag() == Self::DOUBLE_PUSH
     }
-    pub fn black_cache(&self) -> Option<bool> {
-        Self::decode_option_bool((self.0 >> Self::BLACK_CACHE_SHIFT) & 0b11)
+    pub fn promoted_to(&self) -> Option<Piece> {
+        let flag = self.flag();
+        (flag & Self::PROMOTION != 0).then(|| unsafe { std::mem::transmute((flag & 0b11) + 1) })
     }
-    pub fn prev_half_moves(&self) -> u8 {
-        ((self.0 >> Self::PREV_HALF_MOVE_SHIFT) & 0xFF) as u8
+
+    /// Long algebraic coordinates as used by UCI, e.g. `e2e4` or `e7e8q`.
+    pub fn to_uci(&self) -> String {
+        let mut s = format!("{}{}", self.from(), self.to());
+        if let Some(p) = self.promoted_to() {
+            s.push(p.to_fen_char(false));
+        }
+        s
     }
 }
 
-impl Default for Move {
-    fn default() -> Self {
-        Self(0)
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_uci())
     }
-}
\ No newline at end of file
+}
+
+/// A move together with the pieces it involves, see `Board::decode`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodedMove {
+    pub mov: Move,
+    pub piece: Piece,
+    pub capture: Option<Piece>,
+}
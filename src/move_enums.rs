@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::Tile;
+use crate::{CastlingRights, Tile};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MoveError {
@@ -43,4 +43,138 @@ pub enum GameState {
 pub enum MoveResult {
     MoveApplied(GameState),
     PromotionNeeded(Tile),
-}
\ No newline at end of file
+}
+/// Which part of the legal moves a generator produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenType {
+    /// Every legal move.
+    Legal,
+    /// Captures, en passant and every promotion.
+    Captures,
+    /// Everything else, including castling.
+    Quiets,
+    /// Every legal move while in check, nothing otherwise.
+    Evasions,
+    /// Quiet moves that give check.
+    QuietChecks,
+}
+
+/// How forgiving FEN parsing is about the fields after the piece placement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FenMode {
+    /// Every field must be present and well formed.
+    Strict,
+    /// Like `Strict`, but missing clocks default to `0 1` and anything after them is ignored.
+    /// What `Board::new_from_fen` uses.
+    OptionalClocks,
+    /// Missing or malformed fields fall back to `w - - 0 1`.
+    Lenient,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FenError {
+    /// Fields are counted from 0, the piece placement.
+    MissingField { field: usize },
+    InvalidField { field: usize, text: String },
+    ExtraField { field: usize, text: String },
+    /// The FEN parsed but describes an impossible position, see `Board::validate`.
+    IllegalPosition(Vec<PositionError>),
+}
+impl FenError {
+    pub const FIELD_NAMES: [&str; 6] = [
+        "piece placement",
+        "active color",
+        "castling rights",
+        "en passant square",
+        "half move clock",
+        "full move number",
+    ];
+}
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = |field: &usize| Self::FIELD_NAMES.get(*field).copied().unwrap_or("field");
+        match self {
+            FenError::MissingField { field } => write!(f, "Missing {} in FEN", name(field)),
+            FenError::InvalidField { field, text } => write!(f, "Invalid {} in FEN: '{}'", name(field), text),
+            FenError::ExtraField { field, text } => write!(f, "Unexpected field {} in FEN: '{}'", field, text),
+            FenError::IllegalPosition(errors) => {
+                write!(f, "Illegal position:")?;
+                for e in errors {
+                    write!(f, " {}.", e)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+impl std::error::Error for FenError {}
+
+/// Structural problems found by `Board::validate`, colours are `true` for white.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionError {
+    MissingKing(bool),
+    TooManyKings(bool),
+    TooManyPawns(bool),
+    TooManyPieces(bool),
+    PawnOnBackRank(Tile),
+    OpponentInCheck,
+    InvalidEnPassant(Tile),
+    InvalidCastling(CastlingRights),
+}
+impl fmt::Display for PositionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let side = |white: &bool| if *white { "White" } else { "Black" };
+        match self {
+            PositionError::MissingKing(white) => write!(f, "{} has no king", side(white)),
+            PositionError::TooManyKings(white) => write!(f, "{} has more than one king", side(white)),
+            PositionError::TooManyPawns(white) => write!(f, "{} has more than 8 pawns", side(white)),
+            PositionError::TooManyPieces(white) => write!(f, "{} has more than 16 pieces", side(white)),
+            PositionError::PawnOnBackRank(tile) => write!(f, "Pawn on the back rank at {}", tile),
+            PositionError::OpponentInCheck => write!(f, "The side not to move is in check"),
+            PositionError::InvalidEnPassant(tile) => write!(f, "No pawn can be taken en passant on {}", tile),
+            PositionError::InvalidCastling(rights) => write!(f, "Castling rights {} don't match the pieces", rights.to_fen()),
+        }
+    }
+}
+
+/// Why `Board::parse_uci_move` rejected its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UciMoveError {
+    /// Not coordinates like `e2e4` or `e7e8q`.
+    Unparseable(String),
+    /// Well formed but not a legal move in the position.
+    Illegal(String),
+}
+impl fmt::Display for UciMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UciMoveError::Unparseable(s) => write!(f, "'{}' is not a coordinate move", s),
+            UciMoveError::Illegal(s) => write!(f, "{} is not legal here", s),
+        }
+    }
+}
+impl std::error::Error for UciMoveError {}
+
+/// Why `Board::parse_san` rejected its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SanError {
+    /// Not recognisable as S.A.N.
+    Unparseable,
+    /// No legal move matches.
+    Illegal,
+    /// More than one legal move matches.
+    Ambiguous,
+    /// Only another kind of piece can make the move, like `Bf3` when just a knight reaches f3.
+    WrongPiece,
+}
+impl fmt::Display for SanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SanError::Unparseable => write!(f, "Not a S.A.N move"),
+            SanError::Illegal => write!(f, "No legal move matches"),
+            SanError::Ambiguous => write!(f, "More than one legal move matches"),
+            SanError::WrongPiece => write!(f, "Only a different piece can make that move"),
+        }
+    }
+}
+impl std::error::Error for SanError {}
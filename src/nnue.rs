@@ -0,0 +1,302 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt::{self, Debug, Display};
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::{Board, Evaluator, Piece, Player, Tile};
+
+pub const MAGIC: [u8; 4] = *b"HKP1";
+/// King tiles times the ten non king pieces on every tile.
+pub const FEATURES: usize = 64 * 10 * 64;
+// Hidden sums are shifted down by this before clipping
+const HIDDEN_SHIFT: u32 = 6;
+// Output units per centipawn
+const OUTPUT_SCALE: i32 = 16;
+const MAX_LAYER_SIZE: u32 = 4096;
+
+#[derive(Debug)]
+pub enum NnueError {
+    Io(io::Error),
+    BadMagic,
+    InvalidSize { accumulator: u32, hidden: u32 },
+    Truncated,
+    TrailingData,
+}
+
+impl Display for NnueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NnueError::Io(e) => write!(f, "{}", e),
+            NnueError::BadMagic => write!(f, "not a HalfKP network"),
+            NnueError::InvalidSize { accumulator, hidden } => {
+                write!(f, "invalid layer sizes {} and {}", accumulator, hidden)
+            }
+            NnueError::Truncated => write!(f, "network file is truncated"),
+            NnueError::TrailingData => write!(f, "unexpected data after the network"),
+        }
+    }
+}
+
+impl std::error::Error for NnueError {}
+
+impl From<io::Error> for NnueError {
+    fn from(e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::UnexpectedEof => NnueError::Truncated,
+            _ => NnueError::Io(e),
+        }
+    }
+}
+
+/// A HalfKP network. Each side has an accumulator over (own king tile, piece, tile) features for every
+/// piece but the kings, black seeing the board flipped. Both accumulators, side to move first, are
+/// clipped to `0..=127` and go through one clipped hidden layer to the output.
+pub struct Network {
+    size: usize,
+    hidden: usize,
+    feature_biases: Vec<i16>,
+    feature_weights: Vec<i16>,
+    hidden_biases: Vec<i32>,
+    hidden_weights: Vec<i8>,
+    output_bias: i32,
+    output_weights: Vec<i8>,
+}
+
+impl Debug for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Network").field("size", &self.size).field("hidden", &self.hidden).finish_non_exhaustive()
+    }
+}
+
+impl Network {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, NnueError> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+
+    /// Little endian, in order: the magic `HKP1`, the accumulator size `N` and hidden size `H` as `u32`,
+    /// `N` feature biases and `FEATURES * N` feature weights (feature major) as `i16`, `H` hidden biases
+    /// as `i32`, `H * 2N` hidden weights (output major) as `i8`, the output bias as `i32` and `H` output
+    /// weights as `i8`.
+    pub fn read(mut reader: impl Read) -> Result<Self, NnueError> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(NnueError::BadMagic);
+        }
+        let accumulator = read_u32(&mut reader)?;
+        let hidden = read_u32(&mut reader)?;
+        if !(1..=MAX_LAYER_SIZE).contains(&accumulator) || !(1..=MAX_LAYER_SIZE).contains(&hidden) {
+            return Err(NnueError::InvalidSize { accumulator, hidden });
+        }
+        let (size, hidden) = (accumulator as usize, hidden as usize);
+
+        let network = Network {
+            size,
+            hidden,
+            feature_biases: read_i16s(&mut reader, size)?,
+            feature_weights: read_i16s(&mut reader, FEATURES * size)?,
+            hidden_biases: read_i32s(&mut reader, hidden)?,
+            hidden_weights: read_i8s(&mut reader, hidden * 2 * size)?,
+            output_bias: read_u32(&mut reader)? as i32,
+            output_weights: read_i8s(&mut reader, hidden)?,
+        };
+        if reader.read(&mut [0])? != 0 {
+            return Err(NnueError::TrailingData);
+        }
+        Ok(network)
+    }
+
+    /// Accumulator size per side.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    // Centipawns for the side whose accumulator is `us`
+    fn output(&self, us: &[i16], them: &[i16]) -> i32 {
+        let input: Vec<i32> = us.iter().chain(them).map(|&v| (v as i32).clamp(0, 127)).collect();
+        let mut output = self.output_bias;
+        for (j, row) in self.hidden_weights.chunks_exact(2 * self.size).enumerate() {
+            let sum = self.hidden_biases[j] + row.iter().zip(&input).map(|(&w, &x)| w as i32 * x).sum::<i32>();
+            let activation = (sum >> HIDDEN_SHIFT).clamp(0, 127);
+            output += self.output_weights[j] as i32 * activation;
+        }
+        output / OUTPUT_SCALE
+    }
+
+    fn weights(&self, feature: usize) -> &[i16] {
+        &self.feature_weights[feature * self.size..(feature + 1) * self.size]
+    }
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+fn read_bytes(reader: &mut impl Read, len: usize) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![0; len];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+fn read_i8s(reader: &mut impl Read, count: usize) -> io::Result<Vec<i8>> {
+    Ok(read_bytes(reader, count)?.into_iter().map(|b| b as i8).collect())
+}
+fn read_i16s(reader: &mut impl Read, count: usize) -> io::Result<Vec<i16>> {
+    let bytes = read_bytes(reader, count * 2)?;
+    Ok(bytes.chunks_exact(2).map(|b| i16::from_le_bytes([b[0], b[1]])).collect())
+}
+fn read_i32s(reader: &mut impl Read, count: usize) -> io::Result<Vec<i32>> {
+    let bytes = read_bytes(reader, count * 4)?;
+    Ok(bytes.chunks_exact(4).map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect())
+}
+
+// Feature of a piece as seen from `perspective`'s king
+fn feature(perspective: bool, king: Tile, piece: Piece, white: bool, tile: Tile) -> usize {
+    let orient = |tile: Tile| if perspective { tile.to_usize() } else { tile.to_usize() ^ 56 };
+    let colour = if white == perspective { 0 } else { 1 };
+    (orient(king) * 10 + piece as usize * 2 + colour) * 64 + orient(tile)
+}
+
+/// Accumulators of both sides, kept up to date by the board once enabled with `Board::enable_nnue`.
+#[derive(Clone)]
+pub(crate) struct NnueState {
+    pub(crate) network: Arc<Network>,
+    // Indexed white then black
+    accumulators: [Vec<i16>; 2],
+}
+
+impl NnueState {
+    fn new(network: Arc<Network>, white: &Player, black: &Player) -> Self {
+        let accumulators = [network.feature_biases.clone(), network.feature_biases.clone()];
+        let mut state = Self { network, accumulators };
+        state.refresh(true, white, black);
+        state.refresh(false, white, black);
+        state
+    }
+
+    // Rebuild one side from scratch, needed whenever its king moves
+    fn refresh(&mut self, perspective: bool, white: &Player, black: &Player) {
+        let acc = &mut self.accumulators[side_index(perspective)];
+        acc.copy_from_slice(&self.network.feature_biases);
+        let own = if perspective { white } else { black };
+        let Some(king) = own.bb[Piece::King as usize].iter().next() else {
+            return;
+        };
+        for (player, player_white) in [(white, true), (black, false)] {
+            for piece in Piece::ALL_PIECES.into_iter().filter(|&p| p != Piece::King) {
+                for tile in player.bb[piece as usize].iter() {
+                    let weights = self.network.weights(feature(perspective, king, piece, player_white, tile));
+                    for (a, w) in acc.iter_mut().zip(weights) {
+                        *a += w;
+                    }
+                }
+            }
+        }
+    }
+
+    // Called once the piece is on the board
+    pub(crate) fn placed(&mut self, piece: Piece, white: bool, tile: Tile, players: (&Player, &Player)) {
+        if piece == Piece::King {
+            self.refresh(white, players.0, players.1);
+        } else {
+            self.update(piece, white, tile, players, 1);
+        }
+    }
+
+    // Called once the piece is on `to`, a king move rebuilds its own side
+    pub(crate) fn moved(&mut self, piece: Piece, white: bool, from: Tile, to: Tile, players: (&Player, &Player)) {
+        if piece == Piece::King {
+            self.refresh(white, players.0, players.1);
+        } else {
+            self.update(piece, white, from, players, -1);
+            self.update(piece, white, to, players, 1);
+        }
+    }
+
+    // Called once the piece is off the board. A missing king leaves its side stale until it's placed again
+    pub(crate) fn removed(&mut self, piece: Piece, white: bool, tile: Tile, players: (&Player, &Player)) {
+        if piece != Piece::King {
+            self.update(piece, white, tile, players, -1);
+        }
+    }
+
+    fn update(&mut self, piece: Piece, white: bool, tile: Tile, players: (&Player, &Player), sign: i16) {
+        for (perspective, player) in [(true, players.0), (false, players.1)] {
+            let Some(king) = player.bb[Piece::King as usize].iter().next() else {
+                continue;
+            };
+            let weights = self.network.weights(feature(perspective, king, piece, white, tile));
+            for (a, w) in self.accumulators[side_index(perspective)].iter_mut().zip(weights) {
+                *a += sign * w;
+            }
+        }
+    }
+
+    fn evaluate(&self, white_turn: bool) -> i32 {
+        let us = &self.accumulators[side_index(white_turn)];
+        let them = &self.accumulators[side_index(!white_turn)];
+        self.network.output(us, them)
+    }
+}
+
+impl Debug for NnueState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NnueState").field("network", &self.network).finish_non_exhaustive()
+    }
+}
+
+fn side_index(white: bool) -> usize {
+    if white { 0 } else { 1 }
+}
+
+/// Evaluates with a network, using the board's accumulators when they were built from the same one.
+#[derive(Debug, Clone)]
+pub struct NnueEvaluator {
+    network: Arc<Network>,
+}
+
+impl NnueEvaluator {
+    pub fn new(network: Arc<Network>) -> Self {
+        Self { network }
+    }
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, NnueError> {
+        Ok(Self::new(Arc::new(Network::load(path)?)))
+    }
+    pub fn network(&self) -> &Arc<Network> {
+        &self.network
+    }
+}
+
+impl Evaluator for NnueEvaluator {
+    fn evaluate(&self, board: &Board) -> i32 {
+        if board.white.bb[Piece::King as usize].none() || board.black.bb[Piece::King as usize].none() {
+            return 0;
+        }
+        match &board.nnue {
+            Some(state) if Arc::ptr_eq(&state.network, &self.network) => state.evaluate(board.white_turn),
+            _ => NnueState::new(self.network.clone(), &board.white, &board.black).evaluate(board.white_turn),
+        }
+    }
+
+    fn prepare(&self, board: &mut Board) {
+        board.enable_nnue(self.network.clone());
+    }
+}
+
+impl Board {
+    /// Start keeping NNUE accumulators up to date through every piece placed or removed.
+    pub fn enable_nnue(&mut self, network: Arc<Network>) {
+        self.nnue = Some(NnueState::new(network, &self.white, &self.black));
+    }
+    pub fn disable_nnue(&mut self) {
+        self.nnue = None;
+    }
+    /// The accumulator of one side, if NNUE is enabled.
+    pub fn nnue_accumulator(&self, white: bool) -> Option<&[i16]> {
+        self.nnue.as_ref().map(|state| state.accumulators[side_index(white)].as_slice())
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use crate::nnue::{FEATURES, MAGIC, NnueState};
+use crate::{Board, Evaluator, Network, Piece, Tile, NnueError, NnueEvaluator, SearchLimits};
+
+const SIZE: usize = 8;
+const HIDDEN: usize = 4;
+
+// A small network with deterministic pseudo random weights
+fn network_bytes() -> Vec<u8> {
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+    let mut next = |range: i64| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % (2 * range as u64 + 1)) as i64 - range
+    };
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend((SIZE as u32).to_le_bytes());
+    bytes.extend((HIDDEN as u32).to_le_bytes());
+    for _ in 0..SIZE {
+        bytes.extend((40 + next(20) as i16).to_le_bytes());
+    }
+    for _ in 0..FEATURES * SIZE {
+        bytes.extend((next(8) as i16).to_le_bytes());
+    }
+    for _ in 0..HIDDEN {
+        bytes.extend((next(500) as i32).to_le_bytes());
+    }
+    for _ in 0..HIDDEN * 2 * SIZE {
+        bytes.push(next(4) as i8 as u8);
+    }
+    bytes.extend(10i32.to_le_bytes());
+    for _ in 0..HIDDEN {
+        bytes.push(next(8) as i8 as u8);
+    }
+    bytes
+}
+
+fn network() -> Arc<Network> {
+    Arc::new(Network::read(network_bytes().as_slice()).unwrap())
+}
+
+#[test]
+fn load() {
+    let path = std::env::temp_dir().join(format!("chess_lib_nnue_{}.bin", std::process::id()));
+    std::fs::write(&path, network_bytes()).unwrap();
+    let evaluator = NnueEvaluator::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(evaluator.unwrap().network().size(), SIZE);
+
+    let bytes = network_bytes();
+    let mut bad_magic = bytes.clone();
+    bad_magic[0] = b'X';
+    assert!(matches!(Network::read(bad_magic.as_slice()), Err(NnueError::BadMagic)));
+    assert!(matches!(Network::read(&bytes[..bytes.len() - 1]), Err(NnueError::Truncated)));
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(matches!(Network::read(trailing.as_slice()), Err(NnueError::TrailingData)));
+    let mut empty = bytes[..12].to_vec();
+    empty[4..8].copy_from_slice(&0u32.to_le_bytes());
+    assert!(matches!(Network::read(empty.as_slice()), Err(NnueError::InvalidSize { accumulator: 0, .. })));
+    assert!(matches!(Network::load("does/not/exist.nnue"), Err(NnueError::Io(_))));
+}
+
+#[test]
+fn accumulators_match_refresh() {
+    let network = network();
+    // King moves, castling both ways, en passant, promotions and Chess960 castling
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+    ] {
+        let mut board = Board::new_from_fen(fen).unwrap();
+        board.enable_nnue(network.clone());
+        let before = board.clone();
+        board.for_each_position(3, &mut |board| {
+            let fresh = NnueState::new(network.clone(), &board.white, &board.black);
+            assert_eq!(board.nnue.as_ref().map(|s| &s.accumulators), Some(&fresh.accumulators), "{}", board.to_fen());
+        });
+        assert_eq!(board, before);
+    }
+
+    // Pieces edited outside of a move, kings included
+    let mut board = Board::new();
+    board.enable_nnue(network.clone());
+    board.move_piece(Tile::E1, Tile::E4);
+    board.move_piece(Tile::G8, Tile::F6);
+    board.remove_piece(Tile::D7);
+    board.place_piece(Piece::Queen, true, Tile::D5);
+    let fresh = NnueState::new(network.clone(), &board.white, &board.black);
+    assert_eq!(board.nnue.as_ref().unwrap().accumulators, fresh.accumulators);
+
+    let mut board = Board::new();
+    assert_eq!(board.nnue_accumulator(true), None);
+    board.enable_nnue(network.clone());
+    assert_eq!(board.nnue_accumulator(false).unwrap().len(), SIZE);
+    board.disable_nnue();
+    assert_eq!(board.nnue_accumulator(true), None);
+}
+
+#[test]
+fn evaluate() {
+    let evaluator = NnueEvaluator::new(network());
+    let fen = "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4";
+    let mut board = Board::new_from_fen(fen).unwrap();
+    let scratch = evaluator.evaluate(&board);
+    assert_ne!(scratch, 0);
+    evaluator.prepare(&mut board);
+    assert_eq!(evaluator.evaluate(&board), scratch);
+
+    // Accumulators built from another network are ignored
+    board.enable_nnue(network());
+    assert_eq!(evaluator.evaluate(&board), scratch);
+
+    // Each side sees the board from its own side
+    let mirrored = Board::new_from_fen("rnbqk2r/pppp1ppp/5n2/2b1p3/4P3/2N2N2/PPPP1PPP/R1BQKB1R b KQkq - 4 4").unwrap();
+    assert_eq!(evaluator.evaluate(&mirrored), scratch);
+
+    let result = Board::new().search_with(SearchLimits::depth(3), &evaluator);
+    assert_eq!(result.depth, 3);
+    assert!(result.best_move.is_some());
+}
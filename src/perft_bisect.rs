@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use crate::{Board, DivideDiff, Move, diff_divide};
+
+/// A UCI engine process used as the reference for `go perft`.
+pub struct UciEngine {
+    child: Child,
+    stdin: ChildStdin,
+    // Filled by a reader thread, so a silent engine can time out
+    lines: Receiver<io::Result<String>>,
+    timeout: Duration,
+    chess960: bool,
+}
+
+impl UciEngine {
+    /// Launch the engine and wait until it is ready.
+    pub fn new(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut child = Command::new(path.as_ref())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in stdout.lines() {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut engine = Self { child, stdin, lines, timeout: Duration::from_secs(60), chess960: false };
+        engine.send("uci")?;
+        engine.wait_for("uciok")?;
+        engine.send("isready")?;
+        engine.wait_for("readyok")?;
+        Ok(engine)
+    }
+
+    /// How long the engine may stay silent before a command fails, a minute by default.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Switch `UCI_Chess960`, which makes the engine read and write castling as king takes rook.
+    pub fn set_chess960(&mut self, chess960: bool) -> io::Result<()> {
+        if chess960 != self.chess960 {
+            self.send(&format!("setoption name UCI_Chess960 value {}", chess960))?;
+            self.chess960 = chess960;
+        }
+        Ok(())
+    }
+
+    /// The engine's divide for a position, as UCI moves and node counts.
+    pub fn divide(&mut self, fen: &str, depth: usize) -> io::Result<Vec<(String, u64)>> {
+        self.send(&format!("position fen {}", fen))?;
+        self.send(&format!("go perft {}", depth))?;
+
+        let mut divide = Vec::new();
+        loop {
+            let line = self.read_line()?;
+            if line.starts_with("Nodes searched") {
+                return Ok(divide);
+            }
+            let lower = line.to_ascii_lowercase();
+            if lower.starts_with("unknown command") || lower.starts_with("error") || lower.starts_with("info string error") {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, line));
+            }
+            // Anything else, like other `info string` lines, is skipped
+            if let Some((mov, count)) = line.split_once(':')
+                && let Ok(count) = count.trim().parse()
+            {
+                divide.push((mov.trim().to_string(), count));
+            }
+        }
+    }
+
+    /// `divide` for a board, with `UCI_Chess960` matching `board.chess960` so castling moves compare equal.
+    pub fn divide_board(&mut self, board: &Board, depth: usize) -> io::Result<Vec<(String, u64)>> {
+        self.set_chess960(board.chess960)?;
+        self.divide(&board.to_fen(), depth)
+    }
+
+    fn send(&mut self, command: &str) -> io::Result<()> {
+        writeln!(self.stdin, "{}", command)?;
+        self.stdin.flush()
+    }
+    fn read_line(&mut self) -> io::Result<String> {
+        match self.lines.recv_timeout(self.timeout) {
+            Ok(line) => Ok(line?.trim().to_string()),
+            Err(RecvTimeoutError::Timeout) => {
+                // It may never answer again, don't let `drop` wait on it
+                let _ = self.child.kill();
+                Err(io::Error::new(io::ErrorKind::TimedOut, "engine stopped answering"))
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(io::Error::new(io::ErrorKind::UnexpectedEof, "engine closed its output"))
+            }
+        }
+    }
+    fn wait_for(&mut self, token: &str) -> io::Result<()> {
+        while self.read_line()? != token {}
+        Ok(())
+    }
+}
+
+impl Drop for UciEngine {
+    fn drop(&mut self) {
+        let _ = self.send("quit");
+        let _ = self.child.wait();
+    }
+}
+
+/// A move generation bug narrowed down to a single position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PerftBug {
+    /// The engine plays this move but `generate_legal_moves` does not.
+    Missing { fen: String, mov: String },
+    /// `generate_legal_moves` produces a move the engine does not.
+    Extra { fen: String, mov: Move },
+}
+
+impl Display for PerftBug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PerftBug::Missing { fen, mov } => write!(f, "{}\nmissing move {}", fen, mov),
+            PerftBug::Extra { fen, mov } => write!(f, "{}\nextra move {}", fen, mov.to_uci()),
+        }
+    }
+}
+
+impl Board {
+    /// Compare divides with `engine`, descending into the first child whose count differs.
+    /// Returns `None` if every count agrees at `depth`. Chess960 boards switch the engine to `UCI_Chess960`.
+    pub fn bisect_perft(&self, depth: usize, engine: &mut UciEngine) -> io::Result<Option<PerftBug>> {
+        let mut board = self.clone();
+        for depth in (1..=depth).rev() {
+            let fen = board.to_fen();
+            let ours = board.positions_divide(depth);
+            let theirs = engine.divide_board(&board, depth)?;
+
+            // Missing and extra moves come first in the diff
+            match diff_divide(&ours, &theirs).into_iter().next() {
+                Some(DivideDiff::Missing { mov, .. }) => return Ok(Some(PerftBug::Missing { fen, mov })),
+                Some(DivideDiff::Extra { mov, .. }) => return Ok(Some(PerftBug::Extra { fen, mov })),
+                Some(DivideDiff::Count { mov, .. }) => board.make_move(mov),
+                None => return Ok(None),
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,129 @@
+#![cfg(unix)]
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::{Board, PerftBug, Tile, UciEngine, format_divide};
+
+// Write a shell script answering `go perft <depth>` with canned divides
+fn stand_in(name: &str, responses: &[(usize, String)]) -> PathBuf {
+    let mut script = String::from("#!/bin/sh\nwhile read -r cmd rest; do\n  case \"$cmd\" in\n");
+    script.push_str("    uci) echo 'id name stand-in'; echo uciok ;;\n");
+    script.push_str("    isready) echo readyok ;;\n");
+    script.push_str("    quit) exit 0 ;;\n");
+    script.push_str("    go) case \"$rest\" in\n");
+    for (depth, output) in responses {
+        script.push_str(&format!("      \"perft {}\") cat <<'EOF'\n{}EOF\n      ;;\n", depth, output));
+    }
+    script.push_str("    esac ;;\n  esac\ndone\n");
+    write_script(name, &script)
+}
+
+fn write_script(name: &str, script: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("chess_lib_{}_{}.sh", name, std::process::id()));
+    std::fs::write(&path, script).unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+#[test]
+fn engine_divide() {
+    let mut board = Board::new();
+    let path = stand_in("divide", &[(2, format_divide(&board.positions_divide(2)))]);
+    let mut engine = UciEngine::new(&path).unwrap();
+
+    let divide = engine.divide(&board.to_fen(), 2).unwrap();
+    assert_eq!(divide.len(), 20);
+    assert!(divide.contains(&("e2e4".to_string(), 20)));
+    assert_eq!(board.bisect_perft(2, &mut engine).unwrap(), None);
+    drop(engine);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn bisect_to_missing_move() {
+    let mut board = Board::new_from_fen("4k3/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+
+    // The engine claims one more reply after h1h2, which turns out to be e8c8
+    let mut root = board.positions_divide(2);
+    let (_, count) = root.iter_mut().find(|(m, _)| m.to_uci() == "h1h2").unwrap();
+    *count += 1;
+    let h1h2 = root.iter().find(|(m, _)| m.to_uci() == "h1h2").unwrap().0;
+    board.make_move(h1h2);
+    let child_fen = board.to_fen();
+    let child = format_divide(&board.positions_divide(1)).replacen("\n\n", "\ne8c8: 1\n\n", 1);
+    board.unmake_move();
+
+    let path = stand_in("missing", &[(2, format_divide(&root)), (1, child)]);
+    let mut engine = UciEngine::new(&path).unwrap();
+    let bug = board.bisect_perft(2, &mut engine).unwrap();
+    assert_eq!(bug, Some(PerftBug::Missing { fen: child_fen, mov: "e8c8".to_string() }));
+    drop(engine);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn bisect_to_extra_move() {
+    let mut board = Board::new_from_fen("4k3/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+    let divide = board.positions_divide(1);
+    let output = format_divide(&divide).replace("e1g1: 1\n", "");
+
+    let path = stand_in("extra", &[(1, output)]);
+    let mut engine = UciEngine::new(&path).unwrap();
+    match board.bisect_perft(1, &mut engine).unwrap() {
+        Some(PerftBug::Extra { fen, mov }) => {
+            assert_eq!(fen, board.to_fen());
+            assert_eq!((mov.from(), mov.to()), (Tile::E1, Tile::G1));
+        }
+        other => panic!("expected an extra move, got {:?}", other),
+    }
+    drop(engine);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn chess960_option_is_sent() {
+    let mut board = Board::new_from_fen("4k3/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+    board.chess960 = true;
+    let chess960 = format_divide(&board.positions_divide(1));
+    assert!(chess960.contains("e1h1: 1"));
+    let standard = chess960.replace("e1h1", "e1g1");
+
+    // Castling is only written king takes rook once the option is on
+    let script = format!(
+        "#!/bin/sh\nwhile read -r cmd rest; do\n  case \"$cmd\" in\n    uci) echo uciok ;;\n    isready) echo readyok ;;\n    \
+         setoption) [ \"$rest\" = 'name UCI_Chess960 value true' ] && on=1 ;;\n    \
+         go) if [ -n \"$on\" ]; then cat <<'EOF'\n{}EOF\n    else cat <<'EOF'\n{}EOF\n    fi ;;\n    \
+         quit) exit 0 ;;\n  esac\ndone\n",
+        chess960, standard
+    );
+    let path = write_script("chess960", &script);
+    let mut engine = UciEngine::new(&path).unwrap();
+    assert_eq!(board.bisect_perft(1, &mut engine).unwrap(), None);
+    drop(engine);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn silent_engine_times_out() {
+    let board = Board::new();
+    let path = stand_in("silent", &[]);
+    let mut engine = UciEngine::new(&path).unwrap();
+    engine.set_timeout(Duration::from_millis(200));
+    let error = engine.divide(&board.to_fen(), 1).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::TimedOut);
+    drop(engine);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn error_line_fails_divide() {
+    let board = Board::new();
+    let path = stand_in("error", &[(1, "Unknown command: go perft 1\n".to_string())]);
+    let mut engine = UciEngine::new(&path).unwrap();
+    let error = engine.divide(&board.to_fen(), 1).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    drop(engine);
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt::{self, Display};
+
+use crate::{Board, Move, PerftOptions, format_divide};
+
+/// One line of a perft suite, `FEN ;D1 20 ;D2 400 ...`.
+#[derive(Debug, Clone)]
+pub struct PerftCase {
+    pub fen: String,
+    pub board: Board,
+    // Expected node count per depth
+    pub nodes: Vec<(usize, u64)>,
+}
+
+/// A depth whose node count differs from the suite, with our divide at that depth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerftMismatch {
+    pub fen: String,
+    pub depth: usize,
+    pub expected: u64,
+    pub found: u64,
+    pub divide: Vec<(Move, u64)>,
+    /// Root moves that differ from the reference divide, empty without a reference.
+    pub diff: Vec<DivideDiff>,
+}
+
+/// A root move whose subtree differs between our divide and a reference one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DivideDiff {
+    /// Only in the reference, as it wrote the move.
+    Missing { mov: String, expected: u64 },
+    /// Only in our divide.
+    Extra { mov: Move, found: u64 },
+    Count { mov: Move, expected: u64, found: u64 },
+}
+
+/// Compare our divide with a reference one in UCI notation, missing moves first, then extra moves,
+/// then differing counts.
+pub fn diff_divide(ours: &[(Move, u64)], theirs: &[(String, u64)]) -> Vec<DivideDiff> {
+    let find_ours = |uci: &str| ours.iter().find(|(m, _)| m.to_uci() == uci);
+    let find_theirs = |mov: Move| theirs.iter().find(|(t, _)| *t == mov.to_uci());
+
+    let mut diff: Vec<DivideDiff> = theirs
+        .iter()
+        .filter(|(t, _)| find_ours(t).is_none())
+        .map(|(t, n)| DivideDiff::Missing { mov: t.clone(), expected: *n })
+        .collect();
+    diff.extend(
+        ours.iter()
+            .filter(|&&(mov, _)| find_theirs(mov).is_none())
+            .map(|&(mov, found)| DivideDiff::Extra { mov, found }),
+    );
+    for &(mov, found) in ours {
+        if let Some(&(_, expected)) = find_theirs(mov)
+            && expected != found
+        {
+            diff.push(DivideDiff::Count { mov, expected, found });
+        }
+    }
+    diff
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SuiteReport {
+    pub passed: usize,
+    pub mismatches: Vec<PerftMismatch>,
+}
+
+/// Parse a suite in the `perftsuite.epd` format, blank lines and `#` comments are skipped.
+pub fn parse_perft_suite(text: &str) -> Result<Vec<PerftCase>, String> {
+    let mut cases = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.split(';');
+        let fen = fields.next().unwrap_or_default().trim().to_string();
+        let board = Board::new_from_fen(&fen).map_err(|e| format!("line {}: {}", i + 1, e))?;
+
+        let mut nodes = Vec::new();
+        for field in fields {
+            let field = field.trim();
+            let parsed = field
+                .strip_prefix('D')
+                .and_then(|rest| rest.split_once(char::is_whitespace))
+                .and_then(|(depth, count)| Some((depth.parse().ok()?, count.trim().parse().ok()?)));
+            match parsed {
+                Some(entry) => nodes.push(entry),
+                None => return Err(format!("line {}: invalid depth entry '{}'", i + 1, field)),
+            }
+        }
+        cases.push(PerftCase { fen, board, nodes });
+    }
+    Ok(cases)
+}
+
+/// Run every case up to `max_depth`, stopping a case at its first wrong depth.
+pub fn run_perft_suite(cases: &[PerftCase], max_depth: usize, options: PerftOptions) -> SuiteReport {
+    run_perft_suite_with(cases, max_depth, options, |_, _| None)
+}
+
+/// Same as `run_perft_suite`, diffing each mismatch against `reference(board, depth)`, the divide of a
+/// trusted move generator like `UciEngine::divide_board`. `None` leaves the diff empty.
+pub fn run_perft_suite_with(
+    cases: &[PerftCase],
+    max_depth: usize,
+    options: PerftOptions,
+    mut reference: impl FnMut(&Board, usize) -> Option<Vec<(String, u64)>>,
+) -> SuiteReport {
+    let mut report = SuiteReport::default();
+    for case in cases {
+        for &(depth, expected) in case.nodes.iter().filter(|(d, _)| *d <= max_depth) {
+            let found = case.board.perft(depth, options);
+            if found == expected {
+                report.passed += 1;
+                continue;
+            }
+            let divide = case.board.clone().positions_divide(depth);
+            let diff = reference(&case.board, depth).map_or(Vec::new(), |theirs| diff_divide(&divide, &theirs));
+            report.mismatches.push(PerftMismatch { fen: case.fen.clone(), depth, expected, found, divide, diff });
+            break;
+        }
+    }
+    report
+}
+
+impl Display for PerftMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.fen)?;
+        writeln!(f, "depth {}: expected {}, found {}", self.depth, self.expected, self.found)?;
+        for diff in &self.diff {
+            writeln!(f, "{}", diff)?;
+        }
+        write!(f, "{}", format_divide(&self.divide))
+    }
+}
+
+impl Display for DivideDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DivideDiff::Missing { mov, expected } => write!(f, "missing {}: {}", mov, expected),
+            DivideDiff::Extra { mov, found } => write!(f, "extra {}: {}", mov, found),
+            DivideDiff::Count { mov, expected, found } => {
+                write!(f, "{}: expected {}, found {}", mov, expected, found)
+            }
+        }
+    }
+}
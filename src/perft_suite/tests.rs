@@ -0,0 +1,74 @@
+use crate::{DivideDiff, PerftOptions, format_divide, parse_perft_suite, run_perft_suite, run_perft_suite_with};
+
+static SUITE: &str = include_str!("../../examples/perftsuite.epd");
+
+#[test]
+fn parse_suite() {
+    let cases = parse_perft_suite(SUITE).unwrap();
+    assert_eq!(cases.len(), 10);
+    assert_eq!(cases[0].nodes[..3], [(1, 20), (2, 400), (3, 8_902)]);
+
+    let cases = parse_perft_suite("# comment\n\n8/8/8/8/8/8/6k1/4K2R w K - 0 1; D1 12 ;D2 38\n").unwrap();
+    assert_eq!(cases[0].nodes, vec![(1, 12), (2, 38)]);
+
+    assert!(parse_perft_suite("8/8/8/8/8/8/6k1/4K2R w K - 0 1 ;D1").is_err());
+    assert!(parse_perft_suite("not a fen ;D1 12").is_err());
+}
+
+#[test]
+fn run_suite() {
+    let cases = parse_perft_suite(SUITE).unwrap();
+    let report = run_perft_suite(&cases, 4, PerftOptions::default());
+    assert_eq!(report.mismatches, vec![]);
+    assert_eq!(report.passed, 40);
+}
+
+#[test]
+fn mismatch_has_divide() {
+    let cases = parse_perft_suite("4k3/8/8/8/8/8/8/4K2R w K - 0 1 ;D1 15 ;D2 67 ;D3 1197").unwrap();
+    let report = run_perft_suite(&cases, 3, PerftOptions::default());
+
+    assert_eq!(report.passed, 1);
+    let mismatch = &report.mismatches[0];
+    assert_eq!((mismatch.depth, mismatch.expected, mismatch.found), (2, 67, 66));
+    assert_eq!(mismatch.divide.len(), 15);
+    assert_eq!(mismatch.divide.iter().map(|(_, n)| n).sum::<u64>(), 66);
+}
+
+#[test]
+fn mismatch_diffs_reference() {
+    let fen = "4k3/8/8/8/8/8/8/4K2R w K - 0 1";
+    let cases = parse_perft_suite(&format!("{} ;D1 16", fen)).unwrap();
+
+    // A reference that has an extra move, lacks castling and disagrees on a count
+    let mut calls = Vec::new();
+    let report = run_perft_suite_with(&cases, 1, Default::default(), |board, depth| {
+        calls.push((board.to_fen(), depth));
+        let mut divide: Vec<(String, u64)> = cases[0].board.clone().positions_divide(1)
+            .iter()
+            .filter(|(m, _)| m.to_uci() != "e1g1")
+            .map(|(m, n)| (m.to_uci(), *n))
+            .collect();
+        divide.push(("h1h9".to_string(), 1));
+        divide[0].1 = 2;
+        Some(divide)
+    });
+    assert_eq!(calls, vec![(fen.to_string(), 1)]);
+
+    let mismatch = &report.mismatches[0];
+    let first = mismatch.divide[0].0;
+    let castle = mismatch.divide.iter().find(|(m, _)| m.to_uci() == "e1g1").unwrap().0;
+    assert_eq!(mismatch.diff, vec![
+        DivideDiff::Missing { mov: "h1h9".to_string(), expected: 1 },
+        DivideDiff::Extra { mov: castle, found: 1 },
+        DivideDiff::Count { mov: first, expected: 2, found: 1 },
+    ]);
+    let text = mismatch.to_string();
+    assert!(text.contains("missing h1h9: 1\nextra e1g1: 1\n"));
+    assert!(text.contains(&format!("{}: expected 2, found 1", first)));
+    assert!(text.ends_with(&format_divide(&mismatch.divide)));
+
+    // Without a reference there is nothing to diff
+    let report = run_perft_suite(&cases, 1, PerftOptions::default());
+    assert!(report.mismatches[0].diff.is_empty());
+}
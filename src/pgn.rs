@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests;
+
+pub mod reader;
+pub use reader::{PgnReader, PgnVisitor};
+pub mod writer;
+pub use writer::PgnWriter;
+
+use std::fmt::{self, Display};
+use std::io;
+
+use crate::{FenError, SanError};
+
+#[derive(Debug)]
+pub enum PgnError {
+    Io(io::Error),
+    /// The `FEN` tag of a game could not be parsed.
+    Fen(FenError),
+    /// A SAN token that is not a legal move in `fen`.
+    IllegalMove { line: usize, san: String, fen: String, reason: SanError },
+    Syntax { line: usize, message: String },
+}
+
+impl Display for PgnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgnError::Io(e) => write!(f, "{}", e),
+            PgnError::Fen(e) => write!(f, "invalid FEN tag: {}", e),
+            PgnError::IllegalMove { line, san, fen, reason } => {
+                write!(f, "line {}: '{}' in {}: {}", line, san, fen, reason)
+            }
+            PgnError::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl std::error::Error for PgnError {}
+
+impl From<io::Error> for PgnError {
+    fn from(e: io::Error) -> Self {
+        PgnError::Io(e)
+    }
+}
+
+impl From<FenError> for PgnError {
+    fn from(e: FenError) -> Self {
+        PgnError::Fen(e)
+    }
+}
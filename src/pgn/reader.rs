@@ -0,0 +1,329 @@
+use std::io::BufRead;
+
+use crate::{Board, Move, PgnError};
+
+/// Callbacks for `PgnReader`, every method defaults to doing nothing.
+pub trait PgnVisitor {
+    fn begin_game(&mut self) {}
+    fn tag(&mut self, _name: &str, _value: &str) {}
+    /// Called with the start position once the tags are read.
+    /// Returning `false` skips the movetext without resolving any moves.
+    fn end_tags(&mut self, _board: &Board) -> bool {
+        true
+    }
+    /// A move, with the position before it was played.
+    fn san(&mut self, _board: &Board, _san: &str, _mov: Move) {}
+    /// Numeric annotation glyphs, suffixes like `!?` are passed as their NAG.
+    fn nag(&mut self, _nag: u8) {}
+    fn comment(&mut self, _comment: &str) {}
+    /// A variation replacing the last move, until the matching `end_variation`.
+    fn begin_variation(&mut self) {}
+    fn end_variation(&mut self) {}
+    fn result(&mut self, _result: &str) {}
+    fn end_game(&mut self) {}
+}
+
+enum Token {
+    Comment(String),
+    Nag(u8),
+    Open,
+    Close,
+    Result(String),
+    San(String),
+}
+
+const RESULTS: [&str; 4] = ["1-0", "0-1", "1/2-1/2", "*"];
+
+/// Reads PGN games one at a time from any `BufRead`, a line at a time.
+pub struct PgnReader<R> {
+    reader: R,
+    line: String,
+    pos: usize,
+    line_number: usize,
+}
+
+impl<R: BufRead> PgnReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self { reader, line: String::new(), pos: 0, line_number: 0 }
+    }
+
+    /// Read the next game into `visitor`, returns `false` once the input is exhausted.
+    /// On an error the rest of the game is skipped, so the next call starts at the following one.
+    pub fn read_game(&mut self, visitor: &mut impl PgnVisitor) -> Result<bool, PgnError> {
+        self.skip_whitespace()?;
+        if self.peek()?.is_none() {
+            return Ok(false);
+        }
+
+        visitor.begin_game();
+        match self.read_game_inner(visitor) {
+            Ok(()) => {
+                visitor.end_game();
+                Ok(true)
+            }
+            Err(e) => {
+                self.skip_movetext()?;
+                Err(e)
+            }
+        }
+    }
+
+    fn read_game_inner(&mut self, visitor: &mut impl PgnVisitor) -> Result<(), PgnError> {
+        let mut fen = None;
+        let mut chess960 = false;
+        while self.peek()? == Some('[') {
+            let (name, value) = self.read_tag()?;
+            visitor.tag(&name, &value);
+            match name.as_str() {
+                "FEN" => fen = Some(value),
+                "Variant" => chess960 = value.contains("960"),
+                _ => {}
+            }
+            self.skip_whitespace()?;
+        }
+
+        let mut board = match fen {
+            Some(fen) => Board::new_from_fen(&fen)?,
+            None => Board::new(),
+        };
+        board.chess960 |= chess960;
+        if !visitor.end_tags(&board) {
+            return self.skip_movetext();
+        }
+
+        // Position and move count of each line a variation branched from
+        let mut stack: Vec<(Board, usize)> = Vec::new();
+        let mut moves_in_line = 0;
+        loop {
+            self.skip_whitespace()?;
+            // A new tag section ends a game with a missing result
+            if matches!(self.peek()?, None | Some('[')) {
+                if !stack.is_empty() {
+                    return Err(self.syntax("unterminated variation"));
+                }
+                return Ok(());
+            }
+
+            match self.next_token()? {
+                Token::Comment(comment) => visitor.comment(&comment),
+                Token::Nag(nag) => visitor.nag(nag),
+                Token::Open => {
+                    if moves_in_line == 0 {
+                        return Err(self.syntax("variation before any move"));
+                    }
+                    let parent = board.clone();
+                    board.unmake_move();
+                    stack.push((parent, moves_in_line));
+                    moves_in_line = 0;
+                    visitor.begin_variation();
+                }
+                Token::Close => {
+                    let Some((parent, count)) = stack.pop() else {
+                        return Err(self.syntax("unmatched ')'"));
+                    };
+                    board = parent;
+                    moves_in_line = count;
+                    visitor.end_variation();
+                }
+                Token::Result(result) => {
+                    if !stack.is_empty() {
+                        return Err(self.syntax("unterminated variation"));
+                    }
+                    visitor.result(&result);
+                    return Ok(());
+                }
+                Token::San(san) => {
+                    let mov = match board.parse_san(&san) {
+                        Ok(mov) => mov,
+                        Err(reason) => {
+                            return Err(PgnError::IllegalMove { line: self.line_number, san, fen: board.to_fen(), reason });
+                        }
+                    };
+                    visitor.san(&board, &san, mov);
+                    board.make_move(mov);
+                    moves_in_line += 1;
+                }
+            }
+        }
+    }
+
+    // Consume tokens up to the end of the game without resolving moves
+    fn skip_movetext(&mut self) -> Result<(), PgnError> {
+        loop {
+            self.skip_whitespace()?;
+            if matches!(self.peek()?, None | Some('[')) {
+                return Ok(());
+            }
+            match self.next_token() {
+                Ok(Token::Result(_)) => return Ok(()),
+                Err(PgnError::Io(e)) => return Err(PgnError::Io(e)),
+                Ok(_) => {}
+                Err(_) => self.bump(),
+            }
+        }
+    }
+
+    fn read_tag(&mut self) -> Result<(String, String), PgnError> {
+        self.bump();
+        self.skip_whitespace()?;
+        let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_')?;
+        if name.is_empty() {
+            return Err(self.syntax("missing tag name"));
+        }
+        self.skip_whitespace()?;
+        if self.peek()? != Some('"') {
+            return Err(self.syntax("missing tag value"));
+        }
+        self.bump();
+
+        let mut value = String::new();
+        loop {
+            match self.peek()? {
+                Some('"') => break,
+                Some('\\') => {
+                    self.bump();
+                    match self.peek()? {
+                        Some(c @ ('"' | '\\')) => value.push(c),
+                        _ => return Err(self.syntax("invalid escape in tag value")),
+                    }
+                }
+                Some('\n') | None => return Err(self.syntax("unterminated tag value")),
+                Some(c) => value.push(c),
+            }
+            self.bump();
+        }
+        self.bump();
+
+        self.skip_whitespace()?;
+        if self.peek()? != Some(']') {
+            return Err(self.syntax("missing ']'"));
+        }
+        self.bump();
+        Ok((name, value))
+    }
+
+    fn next_token(&mut self) -> Result<Token, PgnError> {
+        loop {
+            self.skip_whitespace()?;
+            let Some(c) = self.peek()? else {
+                return Err(self.syntax("unexpected end of input"));
+            };
+            let token = match c {
+                '{' => {
+                    self.bump();
+                    let comment = self.take_while(|c| c != '}')?;
+                    if self.peek()?.is_none() {
+                        return Err(self.syntax("unterminated comment"));
+                    }
+                    self.bump();
+                    Token::Comment(comment.trim().to_string())
+                }
+                ';' => {
+                    self.bump();
+                    let comment = self.take_while(|c| c != '\n')?;
+                    Token::Comment(comment.trim().to_string())
+                }
+                '$' => {
+                    self.bump();
+                    let digits = self.take_while(|c| c.is_ascii_digit())?;
+                    Token::Nag(digits.parse().map_err(|_| self.syntax("invalid NAG"))?)
+                }
+                '!' | '?' => {
+                    let suffix = self.take_while(|c| c == '!' || c == '?')?;
+                    let nag = match suffix.as_str() {
+                        "!" => 1,
+                        "?" => 2,
+                        "!!" => 3,
+                        "??" => 4,
+                        "!?" => 5,
+                        "?!" => 6,
+                        _ => return Err(self.syntax("invalid move suffix")),
+                    };
+                    Token::Nag(nag)
+                }
+                '(' => {
+                    self.bump();
+                    Token::Open
+                }
+                ')' => {
+                    self.bump();
+                    Token::Close
+                }
+                '*' => {
+                    self.bump();
+                    Token::Result("*".to_string())
+                }
+                '.' => {
+                    self.bump();
+                    continue;
+                }
+                c if c.is_ascii_alphanumeric() => {
+                    let mut symbol = self.take_while(|c| c.is_ascii_alphanumeric() || "+#=:/-_".contains(c))?;
+                    // Move numbers
+                    if symbol.bytes().all(|b| b.is_ascii_digit()) {
+                        continue;
+                    }
+                    // The `e.p.` some files put after en passant captures stays with the move,
+                    // `parse_san` drops it
+                    let rest = &self.line[self.pos..];
+                    let marker = if symbol.ends_with('e') && rest.starts_with(".p.") {
+                        Some(".p.".len())
+                    } else if rest.trim_start().starts_with("e.p.") {
+                        Some(rest.len() - rest.trim_start().len() + "e.p.".len())
+                    } else {
+                        None
+                    };
+                    if let Some(len) = marker {
+                        symbol.push_str(&rest[..len]);
+                        self.pos += len;
+                    }
+                    if RESULTS.contains(&symbol.as_str()) {
+                        Token::Result(symbol)
+                    } else {
+                        Token::San(symbol)
+                    }
+                }
+                c => return Err(self.syntax(&format!("unexpected '{}'", c))),
+            };
+            return Ok(token);
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<char>, PgnError> {
+        while self.pos >= self.line.len() {
+            self.line.clear();
+            self.pos = 0;
+            if self.reader.read_line(&mut self.line)? == 0 {
+                return Ok(None);
+            }
+            self.line_number += 1;
+            // `%` at the start of a line escapes the whole line
+            if self.line.starts_with('%') {
+                self.pos = self.line.len();
+            }
+        }
+        Ok(self.line[self.pos..].chars().next())
+    }
+    fn bump(&mut self) {
+        if let Some(c) = self.line[self.pos..].chars().next() {
+            self.pos += c.len_utf8();
+        }
+    }
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> Result<String, PgnError> {
+        let mut s = String::new();
+        while let Some(c) = self.peek()? {
+            if !f(c) {
+                break;
+            }
+            s.push(c);
+            self.bump();
+        }
+        Ok(s)
+    }
+    fn skip_whitespace(&mut self) -> Result<(), PgnError> {
+        self.take_while(char::is_whitespace).map(|_| ())
+    }
+    fn syntax(&self, message: &str) -> PgnError {
+        PgnError::Syntax { line: self.line_number, message: message.to_string() }
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{Board, Move, PgnError, PgnReader, PgnVisitor, PgnWriter, SanError};
+
+// Records every callback as a short string
+#[derive(Default)]
+struct Recorder {
+    events: Vec<String>,
+    skip_moves: bool,
+}
+
+impl PgnVisitor for Recorder {
+    fn begin_game(&mut self) {
+        self.events.push("begin".to_string());
+    }
+    fn tag(&mut self, name: &str, value: &str) {
+        self.events.push(format!("[{} {}]", name, value));
+    }
+    fn end_tags(&mut self, board: &Board) -> bool {
+        if *board != Board::new() {
+            self.events.push(format!("fen {}", board.to_fen()));
+        }
+        !self.skip_moves
+    }
+    fn san(&mut self, _board: &Board, san: &str, mov: Move) {
+        self.events.push(format!("{}={}", san, mov.to_uci()));
+    }
+    fn nag(&mut self, nag: u8) {
+        self.events.push(format!("${}", nag));
+    }
+    fn comment(&mut self, comment: &str) {
+        self.events.push(format!("{{{}}}", comment));
+    }
+    fn begin_variation(&mut self) {
+        self.events.push("(".to_string());
+    }
+    fn end_variation(&mut self) {
+        self.events.push(")".to_string());
+    }
+    fn result(&mut self, result: &str) {
+        self.events.push(result.to_string());
+    }
+    fn end_game(&mut self) {
+        self.events.push("end".to_string());
+    }
+}
+
+fn read_all(pgn: &str, recorder: &mut Recorder) -> Vec<Result<bool, String>> {
+    let mut reader = PgnReader::new(pgn.as_bytes());
+    let mut results = Vec::new();
+    loop {
+        let result = reader.read_game(recorder).map_err(|e| e.to_string());
+        results.push(result.clone());
+        if result == Ok(false) {
+            return results;
+        }
+    }
+}
+
+#[test]
+fn tags_and_moves() {
+    let pgn = "[Event \"Casual \\\"blitz\\\"\"]\n[Site \"C:\\\\games\"]\n\n1. e4 e5 2. Nf3 Nc6 3. Bb5 1-0\n";
+    let mut recorder = Recorder::default();
+    assert_eq!(read_all(pgn, &mut recorder), [Ok(true), Ok(false)]);
+    assert_eq!(
+        recorder.events,
+        [
+            "begin", "[Event Casual \"blitz\"]", "[Site C:\\games]",
+            "e4=e2e4", "e5=e7e5", "Nf3=g1f3", "Nc6=b8c6", "Bb5=f1b5", "1-0", "end",
+        ]
+    );
+}
+
+#[test]
+fn comments_nags_and_variations() {
+    let pgn = "1. e4 {best by test} e5 $1 2. Nf3!? (2. f4 exf4 (2... d5) 3. Nf3) ; king's gambit\n\
+               2... Nc6 ?! *";
+    let mut recorder = Recorder::default();
+    assert_eq!(read_all(pgn, &mut recorder), [Ok(true), Ok(false)]);
+    assert_eq!(
+        recorder.events,
+        [
+            "begin", "e4=e2e4", "{best by test}", "e5=e7e5", "$1", "Nf3=g1f3", "$5",
+            "(", "f4=f2f4", "exf4=e5f4", "(", "d5=d7d5", ")", "Nf3=g1f3", ")",
+            "{king's gambit}", "Nc6=b8c6", "$6", "*", "end",
+        ]
+    );
+}
+
+#[test]
+fn fen_start_position() {
+    let pgn = "[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/8/4K2R b K - 0 40\"]\n\n40... Kd7 41. O-O 1/2-1/2";
+    let mut recorder = Recorder::default();
+    assert_eq!(read_all(pgn, &mut recorder), [Ok(true), Ok(false)]);
+    assert_eq!(
+        recorder.events[3..],
+        ["fen 4k3/8/8/8/8/8/8/4K2R b K - 0 40", "Kd7=e8d7", "O-O=e1g1", "1/2-1/2", "end"]
+    );
+}
+
+#[test]
+fn multiple_games_and_escapes() {
+    let pgn = "% exported by a tool\n[Event \"a\"]\n1. d4 0-1\n\n[Event \"b\"]\n1. c4\n\n[Event \"c\"]\n*\n";
+    let mut recorder = Recorder::default();
+    assert_eq!(read_all(pgn, &mut recorder), [Ok(true), Ok(true), Ok(true), Ok(false)]);
+    let games: Vec<_> = recorder.events.split(|e| e == "begin").skip(1).collect();
+    assert_eq!(games[0], ["[Event a]", "d4=d2d4", "0-1", "end"]);
+    // Game without a result ends at the next tag section
+    assert_eq!(games[1], ["[Event b]", "c4=c2c4", "end"]);
+    assert_eq!(games[2], ["[Event c]", "*", "end"]);
+}
+
+#[test]
+fn illegal_move_skips_game() {
+    let pgn = "[Event \"bad\"]\n1. e4 e5 2. Ke3 Nc6 1-0\n[Event \"good\"]\n1. Nf3 *";
+    let mut reader = PgnReader::new(pgn.as_bytes());
+    let mut recorder = Recorder::default();
+    match reader.read_game(&mut recorder) {
+        Err(PgnError::IllegalMove { line, san, reason, .. }) => {
+            assert_eq!((line, san.as_str(), reason), (2, "Ke3", SanError::Illegal))
+        }
+        other => panic!("expected an illegal move, got {:?}", other),
+    }
+    recorder.events.clear();
+    assert!(reader.read_game(&mut recorder).unwrap());
+    assert_eq!(recorder.events, ["begin", "[Event good]", "Nf3=g1f3", "*", "end"]);
+}
+
+#[test]
+fn skip_movetext() {
+    let pgn = "[Event \"a\"]\n1. e4 (1. zz9) e5 1-0\n[Event \"b\"]\n1. d4 *";
+    let mut recorder = Recorder { skip_moves: true, ..Default::default() };
+    assert_eq!(read_all(pgn, &mut recorder), [Ok(true), Ok(true), Ok(false)]);
+    assert_eq!(recorder.events, ["begin", "[Event a]", "end", "begin", "[Event b]", "end"]);
+}
+
+#[test]
+fn syntax_errors() {
+    let cases = [
+        ("1. e4 (1. d4", "unterminated variation"),
+        ("1. e4 ) *", "unmatched ')'"),
+        ("( 1. e4 ) *", "variation before any move"),
+        ("[Event \"open]\n*", "unterminated tag value"),
+        ("1. e4 {never closed", "unterminated comment"),
+    ];
+    for (pgn, message) in cases {
+        let mut reader = PgnReader::new(pgn.as_bytes());
+        match reader.read_game(&mut Recorder::default()) {
+            Err(PgnError::Syntax { message: m, .. }) => assert_eq!(m, message, "{}", pgn),
+            other => panic!("expected a syntax error for {:?}, got {:?}", pgn, other),
+        }
+    }
+}
+
+#[test]
+fn writer_roster_and_tags() {
+    let mut writer = PgnWriter::default();
+    writer.set_tag("White", "Morphy, Paul");
+    writer.set_tag("Annotator", "a \"quoted\" name");
+    writer.set_tag("ECO", "C20");
+    writer.set_tag("Result", "ignored");
+    let mut board = Board::new();
+    for uci in ["e2e4", "e7e5"] {
+        let mov = board.move_from_algebraic(&uci[2..]).unwrap();
+        writer.push_move(mov);
+        board.make_move(mov);
+    }
+    writer.set_result("1/2-1/2");
+    assert_eq!(
+        writer.finish(),
+        "[Event \"?\"]\n[Site \"?\"]\n[Date \"????.??.??\"]\n[Round \"?\"]\n\
+         [White \"Morphy, Paul\"]\n[Black \"?\"]\n[Result \"1/2-1/2\"]\n\
+         [Annotator \"a \\\"quoted\\\" name\"]\n[ECO \"C20\"]\n\n1. e4 e5 1/2-1/2\n"
+    );
+}
+
+#[test]
+fn writer_from_fen_history() {
+    let mut board = Board::new_from_fen("4k3/8/8/8/8/8/8/R3K3 b Q - 0 40").unwrap();
+    board.make_move_unchecked(board.move_from_algebraic("Kd7").unwrap());
+    board.make_move_unchecked(board.move_from_algebraic("O-O-O").unwrap());
+    let pgn = PgnWriter::from_history(&board).finish();
+    assert!(pgn.contains("[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/8/R3K3 b Q - 0 40\"]\n"));
+    assert!(pgn.ends_with("\n\n40... Kd7 41. O-O-O+ *\n"));
+}
+
+#[test]
+fn writer_numbering_comments_and_variations() {
+    let mut writer = PgnWriter::default();
+    let mut board = Board::new();
+    let play = |writer: &mut PgnWriter, board: &mut Board, san: &str| {
+        let mov = board.move_from_algebraic(san).unwrap();
+        writer.push_move(mov);
+        board.make_move(mov);
+    };
+    play(&mut writer, &mut board, "e4");
+    writer.push_comment("king's pawn");
+    play(&mut writer, &mut board, "e5");
+    writer.push_nag(1);
+    writer.begin_variation();
+    board.unmake_move();
+    play(&mut writer, &mut board, "c5");
+    writer.end_variation();
+    board.unmake_move();
+    board.make_move(board.move_from_algebraic("e5").unwrap());
+    play(&mut writer, &mut board, "Qh5");
+    play(&mut writer, &mut board, "Nc6");
+    play(&mut writer, &mut board, "Bc4");
+    play(&mut writer, &mut board, "Nf6");
+    play(&mut writer, &mut board, "Qxf7");
+    writer.set_result("1-0");
+
+    let pgn = writer.finish();
+    let movetext = pgn.split("\n\n").nth(1).unwrap();
+    assert_eq!(movetext, "1. e4 {king's pawn} 1... e5 $1 (1... c5) 2. Qh5 Nc6 3. Bc4 Nf6 4. Qxf7# 1-0\n");
+}
+
+#[test]
+fn writer_wraps_lines() {
+    let mut writer = PgnWriter::default();
+    let mut board = Board::new();
+    // Knights shuffling back and forth
+    for _ in 0..10 {
+        for san in ["Nf3", "Nf6", "Ng1", "Ng8"] {
+            let mov = board.move_from_algebraic(san).unwrap();
+            writer.push_move(mov);
+            board.make_move(mov);
+        }
+    }
+    writer.push_comment(&"long comment ".repeat(10));
+    let pgn = writer.finish();
+    let movetext = pgn.split("\n\n").nth(1).unwrap();
+    assert!(movetext.lines().count() > 2);
+    assert!(movetext.lines().all(|l| l.len() <= 80 && !l.ends_with(' ')));
+    assert_eq!(movetext.split_whitespace().collect::<Vec<_>>().join(" "), movetext.lines().collect::<Vec<_>>().join(" "));
+
+    writer.set_columns(20);
+    assert!(writer.finish().split("\n\n").nth(1).unwrap().lines().all(|l| l.len() <= 20));
+}
+
+#[test]
+fn writer_keeps_comment_text() {
+    // Parentheses inside a comment are not variations
+    let pgn = "[Event \"?\"]\n[Site \"?\"]\n[Date \"????.??.??\"]\n[Round \"?\"]\n\
+               [White \"?\"]\n[Black \"?\"]\n[Result \"*\"]\n\n\
+               1. e4 {a comment 1-0 ( here} 1... e5 {( ) x )} (1... c5 {in ( a ) line}) *\n";
+    let mut reader = PgnReader::new(pgn.as_bytes());
+    let mut writer = PgnWriter::default();
+    assert!(reader.read_game(&mut writer).unwrap());
+    assert_eq!(writer.finish(), pgn);
+
+    let mut writer = PgnWriter::default();
+    writer.set_columns(10);
+    writer.push_comment("a comment 1-0 ( here");
+    let movetext = writer.finish().split("\n\n").nth(1).unwrap().to_string();
+    assert_eq!(movetext.split_whitespace().collect::<Vec<_>>(), ["{a", "comment", "1-0", "(", "here}", "*"]);
+}
+
+#[test]
+fn reader_writer_round_trip() {
+    let pgn = "[Event \"Round trip\"]\n[Site \"?\"]\n[Date \"2024.01.02\"]\n[Round \"1\"]\n\
+               [White \"A\"]\n[Black \"B\"]\n[Result \"0-1\"]\n[SetUp \"1\"]\n\
+               [FEN \"r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1\"]\n\n\
+               1. O-O {castles} 1... O-O-O $2 (1... Ra2 2. Rxa2 (2. Rfe1+) 2... Kd7) 2. Ra7\n\
+               Rh1+ 3. Kxh1 0-1\n";
+    let mut reader = PgnReader::new(pgn.as_bytes());
+    let mut writer = PgnWriter::default();
+    assert!(reader.read_game(&mut writer).unwrap());
+    assert_eq!(writer.finish(), pgn);
+}
+
+#[test]
+fn real_world_san() {
+    // `e.p.`, a lowercase knight and a wrong check mark are all accepted
+    let pgn = "1. e4 d5 2. e5 f5 3. exf6 e.p. Nc6 4. nf3 Bd7?! 5. Bb5 a6 6. O-O+ *";
+    let mut recorder = Recorder::default();
+    assert_eq!(read_all(pgn, &mut recorder), [Ok(true), Ok(false)]);
+    for event in ["exf6 e.p.=e5f6", "nf3=g1f3", "$6", "O-O+=e1g1", "*"] {
+        assert!(recorder.events.iter().any(|e| e == event), "{}", event);
+    }
+
+    // Also when written without the space
+    let pgn = "1. e4 d5 2. e5 f5 3. exf6e.p. Nc6 *";
+    let mut recorder = Recorder::default();
+    assert_eq!(read_all(pgn, &mut recorder), [Ok(true), Ok(false)]);
+    assert!(recorder.events.iter().any(|e| e == "exf6e.p.=e5f6"), "{:?}", recorder.events);
+}
@@ -0,0 +1,227 @@
+use crate::{Board, Move, PgnVisitor};
+
+const ROSTER: [(&str, &str); 6] = [
+    ("Event", "?"),
+    ("Site", "?"),
+    ("Date", "????.??.??"),
+    ("Round", "?"),
+    ("White", "?"),
+    ("Black", "?"),
+];
+
+/// Builds a game in PGN export format, move by move.
+/// Also a `PgnVisitor`, so games from `PgnReader` can be written straight back out.
+#[derive(Debug, Clone)]
+pub struct PgnWriter {
+    tags: Vec<(String, String)>,
+    start: Board,
+    board: Board,
+    // Positions the open variations branched from
+    stack: Vec<Board>,
+    tokens: Vec<Token>,
+    needs_number: bool,
+    result: String,
+    columns: usize,
+}
+
+// Variation parentheses are kept apart from the words, so a "(" inside a comment is never attached to anything
+#[derive(Debug, Clone)]
+enum Token {
+    Word(String),
+    Open,
+    Close,
+}
+
+impl Default for PgnWriter {
+    fn default() -> Self {
+        Self::new(Board::new())
+    }
+}
+
+impl PgnWriter {
+    /// A game starting from `board`, `SetUp` and `FEN` tags are added unless it is the standard start.
+    pub fn new(board: Board) -> Self {
+        Self {
+            tags: Vec::new(),
+            start: board.clone(),
+            board,
+            stack: Vec::new(),
+            tokens: Vec::new(),
+            needs_number: true,
+            result: "*".to_string(),
+            columns: 80,
+        }
+    }
+    /// The moves in `board.history`, from the position before the first of them.
+    pub fn from_history(board: &Board) -> Self {
+        let mut start = board.clone();
+        for _ in 0..board.history.len() {
+            start.undo_move();
+        }
+        let mut writer = Self::new(start);
+        for &(mov, _) in &board.history {
+            writer.push_move(mov);
+        }
+        writer.set_result(board.pgn_result());
+        writer
+    }
+
+    /// Set a tag, replacing any earlier value. `Result`, `SetUp` and `FEN` are managed by the writer.
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        if matches!(name, "Result" | "SetUp" | "FEN") {
+            return;
+        }
+        match self.tags.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+    /// Maximum line length of the movetext, 80 by default.
+    pub fn set_columns(&mut self, columns: usize) {
+        self.columns = columns;
+    }
+    pub fn set_result(&mut self, result: &str) {
+        self.result = result.to_string();
+    }
+
+    /// Play `mov` in the current line, it must be legal in the current position.
+    pub fn push_move(&mut self, mov: Move) {
+        let number = self.board.full_move;
+        if self.board.white_turn {
+            self.tokens.push(Token::Word(format!("{}.", number)));
+        } else if self.needs_number {
+            self.tokens.push(Token::Word(format!("{}...", number)));
+        }
+
+        let san = self.board.make_move_san(mov);
+        self.tokens.push(Token::Word(san));
+        self.needs_number = false;
+    }
+    pub fn push_nag(&mut self, nag: u8) {
+        self.tokens.push(Token::Word(format!("${}", nag)));
+    }
+    pub fn push_comment(&mut self, comment: &str) {
+        // A brace would end the comment early
+        let comment = comment.replace('}', "");
+        // One word per token so long comments can wrap
+        let words: Vec<&str> = comment.split_whitespace().collect();
+        match words.as_slice() {
+            [] => self.tokens.push(Token::Word("{}".to_string())),
+            [word] => self.tokens.push(Token::Word(format!("{{{}}}", word))),
+            [first, middle @ .., last] => {
+                self.tokens.push(Token::Word(format!("{{{}", first)));
+                self.tokens.extend(middle.iter().map(|w| Token::Word(w.to_string())));
+                self.tokens.push(Token::Word(format!("{}}}", last)));
+            }
+        }
+        self.needs_number = true;
+    }
+    /// Start an alternative to the last move played, it must follow a `push_move`.
+    pub fn begin_variation(&mut self) {
+        self.stack.push(self.board.clone());
+        self.board.unmake_move();
+        self.tokens.push(Token::Open);
+        self.needs_number = true;
+    }
+    pub fn end_variation(&mut self) {
+        if let Some(board) = self.stack.pop() {
+            self.board = board;
+            self.tokens.push(Token::Close);
+            self.needs_number = true;
+        }
+    }
+
+    /// The complete game, tags then wrapped movetext, ending in a newline.
+    pub fn finish(&self) -> String {
+        let mut pgn = String::new();
+        for (name, default) in ROSTER {
+            let value = self.tags.iter().find(|(n, _)| n == name).map_or(default, |(_, v)| v.as_str());
+            pgn.push_str(&format_tag(name, value));
+        }
+        pgn.push_str(&format_tag("Result", &self.result));
+        if self.start.to_fen() != Board::new().to_fen() {
+            pgn.push_str(&format_tag("SetUp", "1"));
+            pgn.push_str(&format_tag("FEN", &self.start.to_fen()));
+        }
+
+        // Everything outside the roster goes after it in ASCII order
+        let mut extra: Vec<_> = self.tags.iter().filter(|(n, _)| !ROSTER.iter().any(|(r, _)| r == n)).collect();
+        extra.sort();
+        for (name, value) in extra {
+            pgn.push_str(&format_tag(name, value));
+        }
+        pgn.push('\n');
+
+        let mut line = String::new();
+        for word in self.words() {
+            if !line.is_empty() && line.len() + 1 + word.len() > self.columns {
+                pgn.push_str(&line);
+                pgn.push('\n');
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&word);
+        }
+        pgn.push_str(&line);
+        pgn.push('\n');
+        pgn
+    }
+
+    // Tokens joined with parentheses attached to their neighbours, ending in the result
+    fn words(&self) -> Vec<String> {
+        let mut words: Vec<String> = Vec::new();
+        let mut open = false;
+        for token in &self.tokens {
+            let text = match token {
+                Token::Word(word) => word.as_str(),
+                Token::Open => "(",
+                Token::Close => ")",
+            };
+            match words.last_mut() {
+                Some(last) if open || matches!(token, Token::Close) => last.push_str(text),
+                _ => words.push(text.to_string()),
+            }
+            open = matches!(token, Token::Open);
+        }
+        words.push(self.result.clone());
+        words
+    }
+}
+
+fn format_tag(name: &str, value: &str) -> String {
+    format!("[{} \"{}\"]\n", name, value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl PgnVisitor for PgnWriter {
+    fn begin_game(&mut self) {
+        *self = Self { columns: self.columns, ..Self::default() };
+    }
+    fn tag(&mut self, name: &str, value: &str) {
+        self.set_tag(name, value);
+    }
+    fn end_tags(&mut self, board: &Board) -> bool {
+        self.start = board.clone();
+        self.board = board.clone();
+        true
+    }
+    fn san(&mut self, _board: &Board, _san: &str, mov: Move) {
+        self.push_move(mov);
+    }
+    fn nag(&mut self, nag: u8) {
+        self.push_nag(nag);
+    }
+    fn comment(&mut self, comment: &str) {
+        self.push_comment(comment);
+    }
+    fn begin_variation(&mut self) {
+        PgnWriter::begin_variation(self);
+    }
+    fn end_variation(&mut self) {
+        PgnWriter::end_variation(self);
+    }
+    fn result(&mut self, result: &str) {
+        self.set_result(result);
+    }
+}
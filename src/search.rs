@@ -0,0 +1,298 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+use crate::eval::{Evaluator, PIECE_VALUES, TaperedEvaluator};
+use crate::{Board, GenType, Move, MoveList, Piece};
+
+const INFINITY: i32 = 32_000;
+const MATE: i32 = 31_000;
+pub const MAX_PLY: usize = 64;
+// Half width of the first aspiration window, doubled on every fail
+const ASPIRATION: i32 = 50;
+
+/// A score from the side to move's point of view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Score {
+    /// Centipawns.
+    Cp(i32),
+    /// Mate in this many moves, negative when the side to move is the one getting mated.
+    Mate(i32),
+}
+
+impl Score {
+    fn from_internal(score: i32) -> Self {
+        let bound = MATE - MAX_PLY as i32;
+        if score >= bound {
+            Score::Mate((MATE - score + 1) / 2)
+        } else if score <= -bound {
+            Score::Mate(-(MATE + score) / 2)
+        } else {
+            Score::Cp(score)
+        }
+    }
+}
+
+impl Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Score::Cp(cp) => write!(f, "cp {}", cp),
+            Score::Mate(n) => write!(f, "mate {}", n),
+        }
+    }
+}
+
+/// When to stop searching, whichever limit is hit first. Depth 1 always completes.
+/// With no limits at all the search goes to `MAX_PLY`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SearchLimits {
+    pub depth: Option<usize>,
+    pub nodes: Option<u64>,
+    pub time: Option<Duration>,
+}
+
+impl SearchLimits {
+    pub fn depth(depth: usize) -> Self {
+        Self { depth: Some(depth), ..Default::default() }
+    }
+    pub fn nodes(nodes: u64) -> Self {
+        Self { nodes: Some(nodes), ..Default::default() }
+    }
+    pub fn time(time: Duration) -> Self {
+        Self { time: Some(time), ..Default::default() }
+    }
+}
+
+/// Outcome of the last fully searched depth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult {
+    /// `None` only when the side to move has no legal moves.
+    pub best_move: Option<Move>,
+    pub score: Score,
+    pub pv: Vec<Move>,
+    pub depth: usize,
+    pub nodes: u64,
+}
+
+struct Searcher<'a> {
+    board: Board,
+    evaluator: &'a dyn Evaluator,
+    limits: SearchLimits,
+    start: Instant,
+    nodes: u64,
+    // Limits are only checked once depth 1 is done, so there is always a move
+    can_stop: bool,
+    stopped: bool,
+    // Principal variation of the previous iteration, tried first
+    prev_pv: Vec<Move>,
+}
+
+impl Board {
+    /// Iterative deepening negamax with aspiration windows and a quiescence search of captures.
+    pub fn search(&self, limits: SearchLimits) -> SearchResult {
+        self.search_with(limits, &TaperedEvaluator)
+    }
+
+    /// Same as `search`, scoring leaves with `evaluator`.
+    pub fn search_with(&self, limits: SearchLimits, evaluator: &dyn Evaluator) -> SearchResult {
+        let mut board = self.clone();
+        evaluator.prepare(&mut board);
+        let mut searcher = Searcher {
+            board,
+            evaluator,
+            limits,
+            start: Instant::now(),
+            nodes: 0,
+            can_stop: false,
+            stopped: false,
+            prev_pv: Vec::new(),
+        };
+
+        let max_depth = limits.depth.unwrap_or(MAX_PLY).clamp(1, MAX_PLY);
+        let mut result = SearchResult { best_move: None, score: Score::Cp(0), pv: Vec::new(), depth: 0, nodes: 0 };
+        let mut score = 0;
+        for depth in 1..=max_depth {
+            let mut pv = Vec::new();
+            let mut delta = ASPIRATION;
+            let (mut alpha, mut beta) = if depth >= 4 { (score - delta, score + delta) } else { (-INFINITY, INFINITY) };
+            loop {
+                let found = searcher.negamax(depth, 0, alpha, beta, &mut pv);
+                if searcher.stopped {
+                    break;
+                }
+                if found <= alpha {
+                    alpha = (alpha - delta).max(-INFINITY);
+                } else if found >= beta {
+                    beta = (beta + delta).min(INFINITY);
+                } else {
+                    score = found;
+                    break;
+                }
+                delta *= 2;
+            }
+            if searcher.stopped {
+                break;
+            }
+
+            result = SearchResult {
+                best_move: pv.first().copied(),
+                score: Score::from_internal(score),
+                pv: pv.clone(),
+                depth,
+                nodes: searcher.nodes,
+            };
+            searcher.prev_pv = pv;
+            searcher.can_stop = true;
+        }
+        result.nodes = searcher.nodes;
+        result
+    }
+}
+
+impl Searcher<'_> {
+    fn negamax(&mut self, depth: usize, ply: usize, mut alpha: i32, beta: i32, pv: &mut Vec<Move>) -> i32 {
+        pv.clear();
+        if self.should_stop() {
+            return 0;
+        }
+        self.nodes += 1;
+
+        let white = self.board.white_turn;
+        if ply > 0 && (self.board.fifty_move_rule() || self.board.is_repetition()) {
+            return 0;
+        }
+        if ply >= MAX_PLY {
+            return self.evaluator.evaluate(&self.board);
+        }
+        // Check extension, so mates and checks aren't cut off at the horizon
+        let in_check = self.board.is_in_check(white);
+        let depth = if in_check { depth + 1 } else { depth };
+        if depth == 0 {
+            return self.quiescence(ply, alpha, beta);
+        }
+
+        let mut moves = MoveList::new();
+        self.board.generate_legal_moves(white, &mut moves);
+        if moves.is_empty() {
+            return if in_check { ply as i32 - MATE } else { 0 };
+        }
+
+        let mut best = -INFINITY;
+        let mut child = Vec::new();
+        for mov in self.order(&moves, self.prev_pv.get(ply).copied()) {
+            self.board.make_move(mov);
+            let score = -self.negamax(depth - 1, ply + 1, -beta, -alpha, &mut child);
+            self.board.unmake_move();
+            if self.stopped {
+                return 0;
+            }
+
+            if score > best {
+                best = score;
+                if score > alpha {
+                    alpha = score;
+                    pv.clear();
+                    pv.push(mov);
+                    pv.extend_from_slice(&child);
+                }
+                if score >= beta {
+                    break;
+                }
+            }
+        }
+        best
+    }
+
+    // Only captures and promotions, or every evasion when in check
+    fn quiescence(&mut self, ply: usize, mut alpha: i32, beta: i32) -> i32 {
+        if self.should_stop() {
+            return 0;
+        }
+        self.nodes += 1;
+
+        let white = self.board.white_turn;
+        let in_check = self.board.is_in_check(white);
+        if ply >= MAX_PLY {
+            return self.evaluator.evaluate(&self.board);
+        }
+
+        let mut best = -INFINITY;
+        let mut moves = MoveList::new();
+        if in_check {
+            self.board.generate_moves(white, GenType::Evasions, &mut moves);
+            if moves.is_empty() {
+                return ply as i32 - MATE;
+            }
+        } else {
+            // Standing pat, the side to move doesn't have to capture
+            best = self.evaluator.evaluate(&self.board);
+            if best >= beta {
+                return best;
+            }
+            alpha = alpha.max(best);
+            self.board.generate_moves(white, GenType::Captures, &mut moves);
+        }
+
+        for mov in self.order(&moves, None) {
+            self.board.make_move(mov);
+            let score = -self.quiescence(ply + 1, -beta, -alpha);
+            self.board.unmake_move();
+            if self.stopped {
+                return 0;
+            }
+
+            if score > best {
+                best = score;
+                alpha = alpha.max(score);
+                if score >= beta {
+                    break;
+                }
+            }
+        }
+        best
+    }
+
+    // The previous best move first, then captures by most valuable victim and least valuable attacker
+    fn order(&self, moves: &MoveList, first: Option<Move>) -> Vec<Move> {
+        let mut scored: Vec<(i32, Move)> = moves
+            .iter()
+            .map(|&mov| {
+                let mut score = 0;
+                if Some(mov) == first {
+                    score += 100_000;
+                }
+                if mov.is_capture() {
+                    let victim = if mov.is_en_passant() {
+                        Piece::Pawn
+                    } else {
+                        self.board.get_piece_at_tile(mov.to()).map_or(Piece::Pawn, |(p, _)| p)
+                    };
+                    let attacker = self.board.get_piece_at_tile(mov.from()).map_or(Piece::Pawn, |(p, _)| p);
+                    score += 10 * PIECE_VALUES[victim as usize] - PIECE_VALUES[attacker as usize] / 10;
+                }
+                if let Some(promotion) = mov.promoted_to() {
+                    score += PIECE_VALUES[promotion as usize];
+                }
+                (score, mov)
+            })
+            .collect();
+        scored.sort_by_key(|&(score, _)| -score);
+        scored.into_iter().map(|(_, mov)| mov).collect()
+    }
+
+    fn should_stop(&mut self) -> bool {
+        if self.stopped {
+            return true;
+        }
+        if !self.can_stop {
+            return false;
+        }
+        let out_of_nodes = self.limits.nodes.is_some_and(|n| self.nodes >= n);
+        // Reading the clock every node is too slow
+        let out_of_time = self.nodes.is_multiple_of(1024) && self.limits.time.is_some_and(|t| self.start.elapsed() >= t);
+        self.stopped = out_of_nodes || out_of_time;
+        self.stopped
+    }
+}
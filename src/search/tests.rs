@@ -0,0 +1,86 @@
+use std::time::{Duration, Instant};
+
+use crate::{Board, MoveList, Score, SearchLimits};
+
+fn search(fen: &str, depth: usize) -> crate::SearchResult {
+    Board::new_from_fen(fen).unwrap().search(SearchLimits::depth(depth))
+}
+
+#[test]
+fn finds_mates() {
+    // Back rank mate
+    let result = search("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", 3);
+    assert_eq!(result.best_move.unwrap().to_uci(), "a1a8");
+    assert_eq!(result.score, Score::Mate(1));
+
+    // Doubled rooks, the first one is given up to deflect the defender
+    let result = search("r5k1/5ppp/8/8/8/8/3R1PPP/3R2K1 w - - 0 1", 4);
+    assert_eq!(result.score, Score::Mate(2));
+    assert_eq!(result.pv.len(), 3);
+
+    // The side to move gets mated
+    let result = search("7k/8/8/8/8/8/5PPP/r5K1 w - - 0 1", 3);
+    assert_eq!(result.score, Score::Mate(0));
+    assert_eq!(result.best_move, None);
+    let result = search("k7/8/8/8/8/1r6/r7/6K1 b - - 0 1", 4);
+    assert_eq!(result.score, Score::Mate(1));
+    let result = search("6k1/8/8/8/8/1r6/r7/6K1 w - - 0 1", 4);
+    assert_eq!(result.score, Score::Mate(-1));
+}
+
+#[test]
+fn stalemate_and_draws() {
+    let result = search("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1", 3);
+    assert_eq!((result.best_move, result.score), (None, Score::Cp(0)));
+}
+
+#[test]
+fn material_and_quiescence() {
+    // Takes the hanging queen
+    let result = search("4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1", 2);
+    assert_eq!(result.best_move.unwrap().to_uci(), "d1d5");
+
+    // The pawn is defended, taking it with the queen loses her
+    let result = search("4k3/8/2p5/3p4/8/8/8/3QK3 w - - 0 1", 1);
+    assert_ne!(result.best_move.unwrap().to_uci(), "d1d5");
+    assert!(matches!(result.score, Score::Cp(cp) if cp > 600));
+}
+
+#[test]
+fn pv_is_legal() {
+    let mut board = Board::new_from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3").unwrap();
+    let result = board.search(SearchLimits::depth(4));
+    assert_eq!(result.depth, 4);
+    assert_eq!(result.best_move, result.pv.first().copied());
+    for mov in result.pv {
+        let mut moves = MoveList::new();
+        board.generate_legal_moves(board.white_turn, &mut moves);
+        assert!(moves.contains(&mov), "{} not legal in {}", mov, board.to_fen());
+        board.make_move(mov);
+    }
+}
+
+#[test]
+fn limits() {
+    let board = Board::new();
+    let result = board.search(SearchLimits::nodes(2_000));
+    assert!(result.best_move.is_some());
+    assert!(result.depth >= 1);
+    assert!(result.nodes <= 2_100);
+
+    let start = Instant::now();
+    let result = board.search(SearchLimits::time(Duration::from_millis(100)));
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(result.best_move.is_some());
+
+    // Depth 1 always completes
+    let result = board.search(SearchLimits::nodes(1));
+    assert_eq!(result.depth, 1);
+    assert!(result.best_move.is_some());
+}
+
+#[test]
+fn score_display() {
+    assert_eq!(Score::Cp(-35).to_string(), "cp -35");
+    assert_eq!(Score::Mate(3).to_string(), "mate 3");
+}
@@ -5,7 +5,7 @@ mod constants;
 mod tests;
 
 use crate::{
-    BETWEEN, BISHOP_ATTACKS, BISHOP_MAGICS, Bitboard, KING_ATTACKS, KNIGHT_ATTACKS,
+    BETWEEN, BISHOP_ATTACKS, BISHOP_MAGICS, Bitboard, KING_ATTACKS, KNIGHT_ATTACKS, LINE,
     ROOK_ATTACKS, ROOK_MAGICS, magics::magic_index,
 };
 
@@ -26,6 +26,7 @@ impl Tile {
         }
         Some(Tile((y * 8) + x))
     }
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Tile> {
         let mut chars = s.chars();
         let file = chars.next()?;
@@ -144,6 +145,10 @@ impl Tile {
     pub fn get_between(&self, to: Tile) -> Bitboard {
         Bitboard::new(BETWEEN[self.to_usize()][to.to_usize()])
     }
+    /// The full rank, file or diagonal through both tiles, empty if they are not aligned.
+    pub fn get_line(&self, to: Tile) -> Bitboard {
+        Bitboard::new(LINE[self.to_usize()][to.to_usize()])
+    }
 }
 impl Display for Tile {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
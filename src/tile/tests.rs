@@ -48,4 +48,12 @@ fn offset_out_of_bounds() {
     let tile = Tile::new_xy(0, 0).unwrap();
     assert!(tile.offset(-1, 0).is_none());
     assert!(tile.offset(0, -1).is_none());
-}
\ No newline at end of file
+}
+#[test]
+fn line_through_tiles() {
+    let diagonal = Tile::A1.get_line(Tile::C3);
+    assert!(diagonal.get_bit(Tile::H8));
+    assert_eq!(diagonal.count_ones(), 8);
+    assert_eq!(Tile::D4.get_line(Tile::D7), Tile::D1.get_line(Tile::D2));
+    assert!(Tile::A1.get_line(Tile::B3).none());
+}
@@ -1,7 +1,7 @@
-use crate::{Board, CastlingRights, Piece};
+use crate::{Board, CastlingRights, Piece, Tile};
 
 pub mod consts;
-use consts::{CASTLING, EN_PASSANT, PIECE_SQUARE, SIDE_TO_MOVE};
+use consts::{CASTLING, CASTLING_FILES, EN_PASSANT, PIECE_SQUARE, SIDE_TO_MOVE};
 impl Board
 {
     pub fn to_zobrist_hash(&self) -> u64 {
@@ -10,41 +10,79 @@ impl Board
         let white_pieces = self.white.get_all_pieces();
         let black_pieces = self.black.get_all_pieces();
         for (p, t) in white_pieces {
-            hash ^= PIECE_SQUARE[p.to_zobrist_index(true)][t.to_usize()];
+            hash ^= piece_key(p, true, t);
         }
         for (p, t) in black_pieces {
-            hash ^= PIECE_SQUARE[p.to_zobrist_index(false)][t.to_usize()];
+            hash ^= piece_key(p, false, t);
         }
 
-        if self.white_turn == false {
+        if !self.white_turn {
             hash ^= SIDE_TO_MOVE;
         }
 
-        if self.castling.contains(CastlingRights::WHITE_KINGSIDE) {
-            hash ^= CASTLING[0];
-        }
-        if self.castling.contains(CastlingRights::WHITE_QUEENSIDE) {
-            hash ^= CASTLING[1];
-        }
-        if self.castling.contains(CastlingRights::BLACK_KINGSIDE) {
-            hash ^= CASTLING[2];
-        }
-        if self.castling.contains(CastlingRights::BLACK_QUEENSIDE) {
-            hash ^= CASTLING[3];
+        hash ^= castling_key(self.castling);
+        hash ^= self.en_passant_key();
+
+        hash
+    }
+
+    /// The incrementally maintained key of the current position.
+    pub fn zobrist_key(&self) -> u64 {
+        self.zobrist
+    }
+    /// Recompute the stored key from scratch, needed after writing `castling`, `white_turn` or
+    /// `en_passant` directly instead of through `set_castling`/`set_white_turn`/`set_en_passant`.
+    pub fn refresh_zobrist(&mut self) {
+        self.zobrist = self.to_zobrist_hash();
+    }
+
+    pub fn set_castling(&mut self, castling: CastlingRights) {
+        self.zobrist ^= castling_key(self.castling) ^ castling_key(castling);
+        self.castling = castling;
+    }
+    pub fn set_white_turn(&mut self, white_turn: bool) {
+        if white_turn != self.white_turn {
+            // Whether en passant counts depends on who is to move
+            self.zobrist ^= self.en_passant_key() ^ SIDE_TO_MOVE;
+            self.white_turn = white_turn;
+            self.zobrist ^= self.en_passant_key();
         }
+    }
+    pub fn set_en_passant(&mut self, en_passant: Option<Tile>) {
+        self.zobrist ^= self.en_passant_key();
+        self.en_passant = en_passant;
+        self.zobrist ^= self.en_passant_key();
+    }
 
-        if let Some(tile) = self.en_passant {
-            let (player, _) = self.current_players();
-            for t in player.bb[Piece::Pawn as usize] {
-                if t.pawn_attacks(self.white_turn).get_bit(tile) {
-                    let (file, _) = tile.get_coords();
-        
-                    hash ^= EN_PASSANT[file as usize];
-                    break;
-                }
-            }
+    /// The en passant file only counts when the side to move can actually capture.
+    pub(crate) fn en_passant_key(&self) -> u64 {
+        let Some(tile) = self.en_passant else {
+            return 0;
+        };
+        let (player, _) = self.current_players();
+        let capturers = tile.pawn_attacks(!self.white_turn) & player.bb[Piece::Pawn as usize];
+        if capturers.some() {
+            let (file, _) = tile.get_coords();
+            EN_PASSANT[file as usize]
+        } else {
+            0
         }
+    }
+}
 
-        hash
+#[inline(always)]
+pub fn piece_key(piece: Piece, white: bool, tile: Tile) -> u64 {
+    PIECE_SQUARE[piece.to_zobrist_index(white)][tile.to_usize()]
+}
+
+/// Each held right and the file of its rook, so Chess960 positions castling with different rooks differ.
+#[inline(always)]
+pub fn castling_key(rights: CastlingRights) -> u64 {
+    let mut key = 0;
+    for (i, right) in CastlingRights::EACH.into_iter().enumerate() {
+        if rights.contains(right) {
+            key ^= CASTLING[i] ^ CASTLING_FILES[i][rights.rook_file(right) as usize];
+        }
     }
-}
\ No newline at end of file
+    key
+}
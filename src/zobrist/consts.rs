@@ -18,5 +18,13 @@ pub const SIDE_TO_MOVE: u64 = 0x959fe943c4bcaae1;
 pub const CASTLING: [u64; 16] = [
     0x45ee171ff920ac3a, 0x8201e377ad17c8e1, 0xd7d78b3e17264669, 0xc0d6c36a30cc6013, 0xabe5657f0058dd48, 0x57f223f50bce253e, 0x4f7bece790e6efe8, 0x638dfbd19708af21, 0xe03cb1eaea35d607, 0x1413e8df70df09fa, 0xfae4606a37b62e1a, 0x6feca71869feeeae, 0x8b58afa5040fe25e, 0xd75c3097b4c98829, 0xfd421550f65adf12, 0xc7e77d2a8508ca73, ];
 
+// Rook file of each held right, for Chess960
+pub const CASTLING_FILES: [[u64; 8]; 4] = [
+    [0x14938dbc106c6833, 0x041ec7a77ef04369, 0x44008c0b0b8dc19e, 0xc434a4e2d6ee1ccf, 0x254d2bcf88a5dbe1, 0xb572f966bfa4f11f, 0xe9491652b89a358a, 0xa0a75d8d1ceebd0f],
+    [0x760e564aa1e273fb, 0x784320cdf5cfc0a2, 0xd45c81a0151323c5, 0x2442d04ac9792655, 0x26cbc193bed32025, 0x2e4f28ad105d51cd, 0xf61c627b54b92055, 0xa55969b23b05735c],
+    [0xc7f21a2489fdffab, 0x9a3c66b21360b403, 0x78241897e93af493, 0xc6cb028ba2f050f4, 0x258a0f81947fb662, 0x2dad495e147890f8, 0x8322014c3c12b841, 0xfb5727ec37296c68],
+    [0xb6efcc77ed8444f0, 0x78a911b5c7aabceb, 0x587504235bc98aee, 0x2b9bb607c1f4b883, 0x2eaf16c8f361e0ec, 0xd278f226e4279754, 0xaf6cc293719cb771, 0xf0def170d7ca7403],
+];
+
 pub const EN_PASSANT: [u64; 8] = [
     0x834b3cd063564a06, 0x41e971b8e301dc29, 0x241e5d4965a16af1, 0x9a727b57fb1efcdb, 0x51a57e8013e3b6c1, 0x6aa7a09832ce5b7b, 0x5c7177cd5436bdf6, 0xa02744d2a84ef3f1, ];
\ No newline at end of file